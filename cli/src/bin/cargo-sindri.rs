@@ -1,7 +1,7 @@
-use clap::{command, Parser, Subcommand};
-use sindri::client::{AuthOptions, SindriClient};
+use clap::{Parser, Subcommand};
+use sindri::{blocking::SindriClient, client::AuthOptions};
 
-use sindri_cli::commands::{clone, deploy, login};
+use sindri_cli::commands::{clone, deploy, login, pack, usage};
 
 #[derive(Parser)]
 #[command(name = "cargo", bin_name = "cargo")]
@@ -68,6 +68,38 @@ pub enum Commands {
         #[arg(long)]
         teamname: Option<String>,
     },
+    /// Package a circuit project into an archive
+    Pack {
+        /// Path to circuit project directory
+        #[arg(required = true)]
+        project: String,
+
+        /// List the included and excluded files without packaging the project
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Path of the written archive (defaults to <project name>.tar.gz)
+        #[arg(long)]
+        output: Option<String>,
+    },
+    /// Show proof usage over time
+    Usage {
+        /// Start of the time range as a date or RFC 3339 timestamp (defaults to 30 days ago)
+        #[arg(long)]
+        start: Option<String>,
+
+        /// End of the time range as a date or RFC 3339 timestamp (defaults to now)
+        #[arg(long)]
+        end: Option<String>,
+
+        /// Length of each bin in seconds, or with a unit suffix like 12h, 1d or 1w
+        #[arg(long)]
+        bin_size: Option<String>,
+
+        /// Print an ASCII chart instead of a table
+        #[arg(long)]
+        chart: bool,
+    },
 }
 
 fn main() {
@@ -99,6 +131,21 @@ fn main() {
         } => {
             login(&client, username, password, keyname, teamname);
         }
+        Commands::Pack {
+            project,
+            dry_run,
+            output,
+        } => {
+            pack(&client, project, dry_run, output);
+        }
+        Commands::Usage {
+            start,
+            end,
+            bin_size,
+            chart,
+        } => {
+            usage(&client, start, end, bin_size, chart);
+        }
     }
 }
 
@@ -110,7 +157,7 @@ mod tests {
     use predicates::prelude::*;
     use tempfile::TempDir;
     use wiremock::{
-        matchers::{method, path},
+        matchers::{body_partial_json, method, path},
         ResponseTemplate,
     };
 
@@ -225,4 +272,113 @@ mod tests {
             "API key name must not exceed 32 characters",
         ));
     }
+
+    #[tokio::test]
+    async fn test_cli_usage() {
+        let mock_server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(method("POST"))
+            .and(path("/api/v1/proof/histogram"))
+            .and(body_partial_json(serde_json::json!({
+                "bin_size": 86400,
+                "start_time": "2025-01-01T00:00:00+00:00",
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "bin_size": 86400,
+                "data": [
+                    {"bin": "2025-01-01T00:00:00Z", "ready": 7, "failed": 2},
+                    {"bin": "2025-01-02T00:00:00Z", "queued": 1},
+                ],
+                "start_time": "2025-01-01T00:00:00Z",
+                "end_time": "2025-01-03T00:00:00Z",
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let mut cmd = Command::cargo_bin("cargo-sindri").unwrap();
+        cmd.arg("sindri")
+            .arg("usage")
+            .arg("--start")
+            .arg("2025-01-01")
+            .arg("--bin-size")
+            .arg("1d")
+            .arg("--base-url")
+            .arg(mock_server.uri());
+
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "10 proofs from 2025-01-01 00:00 UTC",
+            ))
+            .stdout(predicate::str::is_match(r"2025-01-01 00:00 +0 +0 +7 +2 +9").unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_cli_usage_bad_bin_size() {
+        let mut cmd = Command::cargo_bin("cargo-sindri").unwrap();
+        cmd.arg("sindri").arg("usage").arg("--bin-size").arg("1y");
+        cmd.assert()
+            .failure()
+            .stderr(predicate::str::contains("\"1y\" is not a valid bin size"));
+    }
+
+    fn create_project(dir: &std::path::Path) {
+        std::fs::write(
+            dir.join("sindri.json"),
+            r#"{"name": "pack-circuit", "circuitType": "circom"}"#,
+        )
+        .unwrap();
+        std::fs::write(dir.join("circuit.circom"), "template A() {}").unwrap();
+        std::fs::write(dir.join(".sindriignore"), "*.log\n").unwrap();
+        std::fs::write(dir.join("build.log"), "compiling").unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_cli_pack_dry_run() {
+        let temp_dir = TempDir::new().unwrap();
+        create_project(temp_dir.path());
+
+        let mut cmd = Command::cargo_bin("cargo-sindri").unwrap();
+        cmd.arg("sindri")
+            .arg("pack")
+            .arg(temp_dir.path())
+            .arg("--dry-run");
+
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains(
+                "2 files included (64 B), 2 excluded",
+            ))
+            .stdout(predicate::str::is_match(r"15 B +circuit\.circom").unwrap())
+            .stdout(predicate::str::contains(
+                "build.log  `*.log` (.sindriignore:1)",
+            ))
+            .stdout(predicate::str::contains(".sindriignore  hidden"))
+            .stdout(predicate::str::contains("of 8.00 GB allowed"));
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_cli_pack() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_dir = temp_dir.path().join("project");
+        std::fs::create_dir(&project_dir).unwrap();
+        create_project(&project_dir);
+        let output = temp_dir.path().join("packed.tar.gz");
+
+        // The manifest is checked against the bundled schema when none is served
+        let mock_server = wiremock::MockServer::start().await;
+        let mut cmd = Command::cargo_bin("cargo-sindri").unwrap();
+        cmd.arg("sindri")
+            .arg("pack")
+            .arg(&project_dir)
+            .arg("--output")
+            .arg(&output)
+            .arg("--base-url")
+            .arg(mock_server.uri());
+
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("Digest: sha256:"));
+        assert!(output.exists());
+    }
 }
@@ -4,7 +4,7 @@ use flate2::read::GzDecoder;
 use regex::Regex;
 use tar::Archive;
 
-use sindri::client::SindriClient;
+use sindri::{blocking::SindriClient, StatusCode};
 
 use crate::handle_operation_error;
 
@@ -38,13 +38,13 @@ pub fn clone(client: &SindriClient, circuit: String, directory: Option<String>)
         }
     };
 
-    match client.clone_circuit_blocking(&circuit, download_path.to_string_lossy().to_string()) {
+    match client.clone_circuit(&circuit, download_path.to_string_lossy().to_string()) {
         Ok(_) => println!(
             "{}",
             console::style("  ✓ Successfully downloaded circuit").cyan()
         ),
         Err(e) => {
-            if e.to_string().contains("404") {
+            if e.status() == Some(StatusCode::NOT_FOUND) {
                 handle_operation_error(
                     "Clone",
                     "Circuit does not exist or you lack permission to access it.",
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use regex::Regex;
-use sindri::{client::SindriClient, CircuitInfo, JobStatus};
+use sindri::{blocking::SindriClient, CircuitInfo, Error};
 
 use crate::handle_operation_error;
 
@@ -43,33 +43,31 @@ pub fn deploy(
         .cyan()
     );
 
-    match client.create_circuit_blocking(project, tags, metadata) {
+    match client.create_circuit(project, tags, metadata) {
         Ok(response) => {
             // Gather circuit identifiers from response
-            let status = *response.status();
-            if status == JobStatus::Ready {
-                let uuid = response.id();
-                let team = response.team_slug();
-                let project_name = response.project_name();
-                let first_tag = response.tags().first().cloned().unwrap_or_default();
+            let uuid = response.id();
+            let team = response.team_slug();
+            let project_name = response.project_name();
+            let first_tag = response.tags().first().cloned().unwrap_or_default();
 
-                println!(
-                    "{}",
-                    console::style("  ✓ Circuit created successfully!").cyan()
-                );
-                println!(
-                    "\n{}",
-                    console::style("To generate a proof from this deployment, you can use either:")
-                        .bold()
-                );
-                println!("• Circuit UUID: {}", console::style(uuid).cyan());
-                println!(
-                    "• Identifier:  {}",
-                    console::style(format!("{}/{}:{}", team, project_name, first_tag)).cyan()
-                );
-            } else {
-                handle_operation_error("Deploy", &response.error().unwrap_or_default())
-            }
+            println!(
+                "{}",
+                console::style("  ✓ Circuit created successfully!").cyan()
+            );
+            println!(
+                "\n{}",
+                console::style("To generate a proof from this deployment, you can use either:")
+                    .bold()
+            );
+            println!("• Circuit UUID: {}", console::style(uuid).cyan());
+            println!(
+                "• Identifier:  {}",
+                console::style(format!("{}/{}:{}", team, project_name, first_tag)).cyan()
+            );
+        }
+        Err(Error::JobFailed { message, .. }) => {
+            handle_operation_error("Deploy", &message.unwrap_or_default())
         }
         Err(e) => handle_operation_error("Deploy", &e.to_string()),
     }
@@ -1,6 +1,6 @@
 use crate::handle_operation_error;
 use dialoguer::{Input, Password, Select};
-use sindri::{client::SindriClient, TeamDetail};
+use sindri::{blocking::SindriClient, TeamDetail};
 
 pub fn login(
     client: &SindriClient,
@@ -49,15 +49,14 @@ pub fn login(
     };
 
     // Generate an initial JWT token for team retrieval
-    let rt = tokio::runtime::Runtime::new().unwrap();
-    let token = match rt.block_on(client.jwt_token_generate(&username, &password)) {
+    let token = match client.jwt_token_generate(&username, &password) {
         Ok(token) => token,
         Err(e) => handle_operation_error("Login", &e.to_string()),
     };
     println!("{}", console::style("  ✓ Valid credentials").cyan());
 
     // Collect list of teams for the user
-    let teams = match rt.block_on(client.teams_jwt_auth(&token)) {
+    let teams = match client.teams_jwt_auth(&token) {
         Ok(teams) => teams,
         Err(e) => handle_operation_error("Login", &e.to_string()),
     };
@@ -92,12 +91,12 @@ pub fn login(
     };
 
     // Generate API key for selected team
-    let api_key = match rt.block_on(client.api_key_select_team(
+    let api_key = match client.api_key_select_team(
         &username,
         &password,
         &name,
         &selected_team.id.to_string(),
-    )) {
+    ) {
         Ok(key) => key,
         Err(e) => handle_operation_error("Login", &e.to_string()),
     };
@@ -7,6 +7,12 @@ pub use deploy::deploy;
 pub mod login;
 pub use login::login;
 
+pub mod pack;
+pub use pack::pack;
+
+pub mod usage;
+pub use usage::usage;
+
 use console::style;
 
 pub fn handle_operation_error(command: &str, message: &str) -> ! {
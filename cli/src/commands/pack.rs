@@ -0,0 +1,83 @@
+use sindri::{blocking::SindriClient, client::format_size, PackagePlan};
+
+use crate::handle_operation_error;
+
+pub fn pack(client: &SindriClient, project: String, dry_run: bool, output: Option<String>) {
+    if dry_run {
+        plan(client, &project);
+        return;
+    }
+
+    let archive = match client.package_project(&project) {
+        Ok(archive) => archive,
+        Err(e) => handle_operation_error("Pack", &e.to_string()),
+    };
+    let output = output.unwrap_or_else(|| {
+        let name = archive
+            .manifest()
+            .map_or("project", |manifest| manifest.name());
+        format!("{name}.tar.gz")
+    });
+    if let Err(e) = std::fs::copy(archive.path(), &output) {
+        handle_operation_error("Pack", &format!("Could not write {output}: {e}"));
+    }
+    println!(
+        "{}",
+        console::style(format!(
+            "  ✓ Wrote {} archive to {}",
+            format_size(archive.size()),
+            output
+        ))
+        .cyan()
+    );
+    println!("• Digest: {}", console::style(archive.digest()).cyan());
+}
+
+fn plan(client: &SindriClient, project: &str) {
+    println!("{}", console::style("Planning package...").bold());
+    let plan = match client.plan_package(project) {
+        Ok(plan) => plan,
+        Err(e) => handle_operation_error("Pack", &e.to_string()),
+    };
+    println!(
+        "{}",
+        console::style(format!(
+            "  ✓ {} files included ({}), {} excluded",
+            plan.included.len(),
+            format_size(plan.total_size()),
+            plan.excluded.len()
+        ))
+        .cyan()
+    );
+    println!();
+    print_report(&plan);
+
+    let projection = format!(
+        "Projected archive size: {} of {} allowed",
+        format_size(plan.compressed_size),
+        format_size(plan.max_size)
+    );
+    if plan.exceeds_max_size() {
+        handle_operation_error("Pack", &projection);
+    }
+    println!("\n{}", console::style(projection).bold());
+}
+
+fn print_report(plan: &PackagePlan) {
+    println!("{}", console::style("Included files").bold());
+    for file in &plan.included {
+        println!("{:>10}  {}", format_size(file.size), file.path.display());
+    }
+    if !plan.excluded.is_empty() {
+        println!("\n{}", console::style("Excluded files").bold());
+    }
+    for excluded in &plan.excluded {
+        let suffix = if excluded.is_dir { "/" } else { "" };
+        println!(
+            "  {}{}  {}",
+            excluded.path.display(),
+            suffix,
+            console::style(&excluded.reason).dim()
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use sindri::{blocking::SindriClient, ProofHistogram};
+
+use crate::handle_operation_error;
+
+/// Width of the longest bar in the chart
+const CHART_WIDTH: i64 = 50;
+
+pub fn usage(
+    client: &SindriClient,
+    start: Option<String>,
+    end: Option<String>,
+    bin_size: Option<String>,
+    chart: bool,
+) {
+    println!("{}", console::style("Fetching proof usage...").bold());
+
+    let start_time = start.map_or_else(
+        || Utc::now() - TimeDelta::days(30),
+        |start| parse_time(&start),
+    );
+    let end_time = end.map(|end| parse_time(&end));
+    let bin_size = bin_size.map(|bin_size| {
+        parse_bin_size(&bin_size).unwrap_or_else(|| {
+            handle_operation_error(
+                "Usage",
+                &format!("\"{bin_size}\" is not a valid bin size, e.g. 3600, 12h, 1d or 1w."),
+            )
+        })
+    });
+
+    let histogram = match client.get_proof_histogram(start_time, end_time, bin_size) {
+        Ok(histogram) => histogram,
+        Err(e) => handle_operation_error("Usage", &e.to_string()),
+    };
+    println!(
+        "{}",
+        console::style(format!(
+            "  ✓ {} proofs from {} to {}",
+            histogram.total(),
+            histogram.start_time.format("%Y-%m-%d %H:%M UTC"),
+            histogram.end_time.format("%Y-%m-%d %H:%M UTC"),
+        ))
+        .cyan()
+    );
+    println!();
+
+    if chart {
+        print_chart(&histogram);
+    } else {
+        print_table(&histogram);
+    }
+}
+
+/// Parses an RFC 3339 timestamp or a `YYYY-MM-DD` date, exiting on invalid input.
+fn parse_time(value: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|time| time.with_timezone(&Utc))
+        .or_else(|_| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map(|date| date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc())
+        })
+        .unwrap_or_else(|_| {
+            handle_operation_error(
+                "Usage",
+                &format!(
+                    "\"{value}\" is not a valid date, e.g. 2025-01-31 or 2025-01-31T12:00:00Z."
+                ),
+            )
+        })
+}
+
+/// Parses a number of seconds with an optional `s`, `m`, `h`, `d` or `w` unit suffix.
+fn parse_bin_size(value: &str) -> Option<Duration> {
+    let (number, unit) = match value.find(|c: char| !c.is_ascii_digit()) {
+        Some(index) => value.split_at(index),
+        None => (value, "s"),
+    };
+    let multiplier = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return None,
+    };
+    let seconds = number.parse::<u64>().ok()?.checked_mul(multiplier)?;
+    (seconds > 0).then(|| Duration::from_secs(seconds))
+}
+
+fn print_table(histogram: &ProofHistogram) {
+    println!(
+        "{}",
+        console::style(format!(
+            "{:<20} {:>8} {:>12} {:>8} {:>8} {:>8}",
+            "Bin start", "Queued", "In Progress", "Ready", "Failed", "Total"
+        ))
+        .bold()
+    );
+    for bin in &histogram.bins {
+        println!(
+            "{:<20} {:>8} {:>12} {:>8} {:>8} {:>8}",
+            bin.bin.format("%Y-%m-%d %H:%M"),
+            bin.queued,
+            bin.in_progress,
+            bin.ready,
+            bin.failed,
+            bin.total()
+        );
+    }
+}
+
+fn print_chart(histogram: &ProofHistogram) {
+    let max_total = histogram
+        .bins
+        .iter()
+        .map(|bin| bin.total())
+        .max()
+        .unwrap_or_default()
+        .max(1);
+    // Scale bars so that the busiest bin fills the chart, rounding up so no proofs are hidden
+    let scale = |count: i64| (count * CHART_WIDTH + max_total - 1) / max_total;
+    for bin in &histogram.bins {
+        let failed = scale(bin.failed) as usize;
+        let other = (scale(bin.total()) as usize).saturating_sub(failed);
+        println!(
+            "{} |{}{} {}",
+            bin.bin.format("%Y-%m-%d %H:%M"),
+            console::style("#".repeat(other)).green(),
+            console::style("x".repeat(failed)).red(),
+            bin.total()
+        );
+    }
+    println!(
+        "\n{} ready, queued or in progress   {} failed",
+        console::style("#").green(),
+        console::style("x").red()
+    );
+}
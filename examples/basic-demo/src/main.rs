@@ -1,5 +1,5 @@
 use serde_json::json;
-use sindri::{client::SindriClient, CircuitInfo, JobStatus};
+use sindri::{client::SindriClient, CircuitInfo, Error};
 
 fn main() {
     // Instruct the client to use this entire package for upload
@@ -17,17 +17,16 @@ fn main() {
         Ok(circuit_info) => {
             circuit_info
         }
+        Err(Error::JobFailed { message, .. }) => {
+            println!("Project build failed: {:?}", message);
+            return;
+        }
         Err(e) => {
             println!("Error deploying project: {:?}", e);
             return;
         }
     };
 
-    if deployment.status() == &JobStatus::Failed {
-        println!("Project build failed: {:?}", deployment.error());
-        return;
-    }
-
     let uuid = deployment.id();
     let project_name = deployment.project_name();
 
@@ -47,17 +46,16 @@ fn main() {
         Ok(proof) => {
             proof
         }
+        Err(Error::JobFailed { message, .. }) => {
+            println!("Proof generation failed: {:?}", message);
+            return;
+        }
         Err(e) => {
             println!("Error requesting proof: {:?}", e);
             return;
         }
     };
 
-    if proof_result.status == JobStatus::Failed {
-        println!("Proof generation failed: {:?}", proof_result.error);
-        return;
-    }
-
     println!("Proof generated successfully");
     println!("Proof: {}", proof_result.proof.unwrap().unwrap());
     println!("Public: {}", proof_result.public.unwrap().unwrap());
@@ -1,5 +1,5 @@
 use funty::Fundamental; // u8 to bool
-use sindri::{client::SindriClient, integrations::sp1_v5::SP1ProofInfo, JobStatus, ProofInput};
+use sindri::{client::SindriClient, integrations::sp1_v5::SP1ProofInfo, Error, ProofInput};
 use sp1_sdk::SP1Stdin;
 
 #[tokio::main]
@@ -14,13 +14,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ];
     let email_pattern = r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$";
 
+    // Your API key is supplied from the environment variable SINDRI_API_KEY
+    // The client is cheap to clone and shares its connection pool between tasks
+    let client = SindriClient::default();
+
     let mut handles = vec![];
     println!("Submitting {} emails", test_emails.len());
     for email in test_emails {
+        let client = client.clone();
         let handle = tokio::spawn(async move {
-            // Your API key is supplied from the environment variable SINDRI_API_KEY
-            let client = SindriClient::default();
-
             let mut stdin = SP1Stdin::new();
             stdin.write(&email_pattern);
             stdin.write(&email);
@@ -36,45 +38,40 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 )
                 .await;
 
-            if proof_info.is_err() {
-                println!(
-                    "Error submitting proof request for {}: {:?}",
-                    email,
-                    proof_info.unwrap().error
-                );
-                return;
-            }
-            let unwrapped_proof_info = proof_info.unwrap();
-            if unwrapped_proof_info.status == JobStatus::Failed {
+            let unwrapped_proof_info = match proof_info {
+                Ok(proof_info) => proof_info,
+                Err(Error::JobFailed { message, .. }) => {
+                    println!("Proof generation failed for {}: {:?}", email, message);
+                    return;
+                }
+                Err(e) => {
+                    println!("Error submitting proof request for {}: {}", email, e);
+                    return;
+                }
+            };
+            let sp1_proof = unwrapped_proof_info.to_sp1_proof_with_public().unwrap();
+            let sindri_verifying_key = unwrapped_proof_info.get_sp1_verifying_key().unwrap();
+
+            if unwrapped_proof_info
+                .verify_sp1_proof_locally(&sindri_verifying_key)
+                .is_err()
+            {
                 println!(
-                    "Proof generation failed for {}: {:?}",
+                    "Proof verification failed for {}: {:?}",
                     email, unwrapped_proof_info.error
                 );
             } else {
-                let sp1_proof = unwrapped_proof_info.to_sp1_proof_with_public().unwrap();
-                let sindri_verifying_key = unwrapped_proof_info.get_sp1_verifying_key().unwrap();
-
-                if unwrapped_proof_info
-                    .verify_sp1_proof_locally(&sindri_verifying_key)
-                    .is_err()
-                {
-                    println!(
-                        "Proof verification failed for {}: {:?}",
-                        email, unwrapped_proof_info.error
-                    );
-                } else {
-                    let public_values = sp1_proof.public_values.to_vec();
-                    let email_valid = public_values[0].as_bool();
-                    let color_code = if email_valid { "\x1b[32m" } else { "\x1b[31m" }; // Green for valid, Red for invalid
-                    let reset_code = "\x1b[0m"; // Reset color
-                    println!(
-                        " ✓ Email '{}' is {}{}{} (ZKP verified)",
-                        email,
-                        color_code,
-                        if email_valid { "valid" } else { "invalid" },
-                        reset_code
-                    );
-                }
+                let public_values = sp1_proof.public_values.to_vec();
+                let email_valid = public_values[0].as_bool();
+                let color_code = if email_valid { "\x1b[32m" } else { "\x1b[31m" }; // Green for valid, Red for invalid
+                let reset_code = "\x1b[0m"; // Reset color
+                println!(
+                    " ✓ Email '{}' is {}{}{} (ZKP verified)",
+                    email,
+                    color_code,
+                    if email_valid { "valid" } else { "invalid" },
+                    reset_code
+                );
             }
         });
         handles.push(handle);
@@ -71,17 +71,14 @@ pub enum ProofCreateError {
 }
 
 /// Create a circuit.
+///
+/// The multipart form data is prepared by the caller, with parts delimited by `boundary`,
+/// so that large projects can be streamed from disk rather than held in memory.
 pub async fn circuit_create(
     configuration: &configuration::Configuration,
-    files: Vec<u8>,
-    meta: Option<std::collections::HashMap<String, String>>,
-    tags: Option<Vec<String>>,
+    multipart_body: reqwest::Body,
+    boundary: &str,
 ) -> Result<models::CircuitInfoResponse, Error<CircuitCreateError>> {
-    // add a prefix to parameters to efficiently prevent name collisions
-    let p_files = files;
-    let p_meta = meta;
-    let p_tags = tags;
-
     let uri_str = format!("{}/api/v1/circuit/create", configuration.base_path);
     let mut req_builder = configuration
         .client
@@ -96,58 +93,11 @@ pub async fn circuit_create(
     if let Some(ref token) = configuration.bearer_access_token {
         req_builder = req_builder.bearer_auth(token.to_owned());
     };
-    // Build the request body directly in order to avoid a streaming request
-    // that is incompatible with the retry middleware
-    let boundary = "----------------------------4ebf00fbcf09";
     req_builder = req_builder.header(
         "Content-Type",
         format!("multipart/form-data; boundary={boundary}"),
     );
-
-    let filename = "rust_sdk_upload.tar.gz";
-    let mut byte_string = Vec::new();
-    byte_string.extend_from_slice(
-        format!(
-            "--{boundary}\r\n\
-            Content-Disposition: form-data; name=\"files\"; filename=\"{filename}\"\r\n\
-            \r\n",
-        )
-        .as_bytes(),
-    );
-    byte_string.extend(p_files);
-    byte_string.extend_from_slice(format!("--{boundary}--\r\n").as_bytes()); // End of files
-    if let Some(p_tags) = p_tags {
-        for tag in p_tags {
-            byte_string.extend_from_slice(
-                format!(
-                    "--{boundary}\r\n\
-                    Content-Disposition: form-data; name=\"tags\"\r\n\
-                    \r\n\
-                    {tag}\r\n"
-                )
-                .as_bytes(),
-            );
-            byte_string.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
-            // End of tag
-        }
-    }
-    if let Some(p_meta) = p_meta {
-        let meta_json = serde_json::to_string(&p_meta)?;
-        byte_string.extend_from_slice(
-            format!(
-                "--{boundary}\r\n\
-           Content-Disposition: form-data; name=\"meta\"\r\n\
-           Content-Type: application/json\r\n\
-            \r\n\
-            {meta_json}\r\n"
-            )
-            .as_bytes(),
-        );
-        byte_string.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
-        // End of meta
-    }
-    let local_var_body = reqwest::Body::from(byte_string);
-    req_builder = req_builder.body(local_var_body);
+    req_builder = req_builder.body(multipart_body);
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
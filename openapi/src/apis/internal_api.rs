@@ -1160,10 +1160,19 @@ pub async fn sindri_manifest_schema(
 /// Upload avatar for the team
 pub async fn team_avatar_upload(
     configuration: &configuration::Configuration,
-    files: Vec<std::path::PathBuf>,
+    files: Vec<u8>,
+    filename: &str,
+    content_type: &str,
 ) -> Result<models::TeamMeResponse, Error<TeamAvatarUploadError>> {
     // add a prefix to parameters to efficiently prevent name collisions
-    let _p_files = files;
+    let p_files = files;
+    // Percent-encode the characters that would otherwise end the quoted
+    // filename or the header line, as browsers do for multipart/form-data
+    let p_filename = filename
+        .replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A");
+    let p_content_type = content_type;
 
     let uri_str = format!("{}/api/v1/team/avatar/upload", configuration.base_path);
     let mut req_builder = configuration
@@ -1179,9 +1188,27 @@ pub async fn team_avatar_upload(
     if let Some(ref token) = configuration.bearer_access_token {
         req_builder = req_builder.bearer_auth(token.to_owned());
     };
-    let multipart_form = reqwest::multipart::Form::new();
-    // TODO: support file upload for 'files' parameter
-    req_builder = req_builder.multipart(multipart_form);
+    // Build the request body directly in order to avoid a streaming request
+    // that is incompatible with the retry middleware
+    let boundary = "----------------------------4ebf00fbcf09";
+    req_builder = req_builder.header(
+        "Content-Type",
+        format!("multipart/form-data; boundary={boundary}"),
+    );
+
+    let mut byte_string = Vec::new();
+    byte_string.extend_from_slice(
+        format!(
+            "--{boundary}\r\n\
+            Content-Disposition: form-data; name=\"files\"; filename=\"{p_filename}\"\r\n\
+            Content-Type: {p_content_type}\r\n\
+            \r\n",
+        )
+        .as_bytes(),
+    );
+    byte_string.extend(p_files);
+    byte_string.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes()); // End of files
+    req_builder = req_builder.body(reqwest::Body::from(byte_string));
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
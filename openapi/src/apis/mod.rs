@@ -106,7 +106,11 @@ pub mod circuits_api;
 #[allow(dead_code)]
 mod internal_api;
 pub use internal_api::{
-    circuit_download, circuit_status, proof_status, user_me, CircuitStatusError, ProofStatusError,
+    circuit_download, circuit_proofs_paginated, circuit_smart_contract_verifier, circuit_status,
+    project_circuits, project_delete, project_detail, project_list, project_proofs_paginated,
+    project_settings, proof_histogram, proof_list_paginated, proof_status, sindri_manifest_schema,
+    team_avatar_upload, team_create, team_detail, team_invite, team_me, team_members,
+    team_remove_member, team_settings, user_me, CircuitStatusError, ProofStatusError,
 };
 pub mod proofs_api;
 pub mod token_api;
@@ -1,5 +1,6 @@
 #![allow(unused_imports)]
 #![allow(clippy::too_many_arguments)]
+#![allow(clippy::derivable_impls, clippy::empty_docs, clippy::manual_map)]
 
 extern crate reqwest;
 extern crate serde;
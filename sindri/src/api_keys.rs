@@ -0,0 +1,226 @@
+//! These methods manage the API keys of the authenticated team, e.g. to rotate keys
+//! programmatically.
+
+use std::time::Duration;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use sindri_openapi::{
+    apis::authorization_api::{apikey_delete, apikey_generate_with_auth, apikey_list},
+    models::ApiKeyResponse,
+};
+use tracing::info;
+
+use crate::{client::SindriClient, utils::parse_api_datetime, Error};
+
+/// Returns whether a key has not been used since `cutoff`.
+///
+/// Keys which were never used are judged by their creation date instead. Keys whose dates
+/// cannot be parsed are never considered stale, so that they are not revoked by mistake.
+fn is_stale(key: &ApiKeyResponse, cutoff: DateTime<Utc>) -> bool {
+    let last_activity = key.date_last_used.as_deref().unwrap_or(&key.date_created);
+    parse_api_datetime(last_activity).is_some_and(|date| date < cutoff)
+}
+
+/// Returns the secret of a newly generated key.
+pub(crate) fn revealed_secret(api_key: &ApiKeyResponse) -> Result<&str, Error> {
+    // The key is only ever revealed once, so a response without it is malformed
+    api_key
+        .api_key
+        .as_deref()
+        .ok_or_else(|| Error::UnexpectedResponse("generated API key was not revealed".to_string()))
+}
+
+impl SindriClient {
+    /// Lists the API keys of the authenticated team.
+    ///
+    /// The secret part of each key is never returned; keys can be told apart by their
+    /// `name`, `prefix` and `suffix`.
+    pub async fn list_api_keys(&self) -> Result<Vec<ApiKeyResponse>, Error> {
+        Ok(apikey_list(&self.config).await?)
+    }
+
+    /// Generates a new API key for the authenticated team, authorized by the client's
+    /// current credentials.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Optional human-readable name of the key
+    ///
+    /// # Returns
+    ///
+    /// Returns the new key, whose secret `api_key` field is only revealed in this response.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use sindri::client::SindriClient;
+    ///
+    /// // Rotate the key in use by the client, leaving the team's other keys alone
+    /// let client = SindriClient::default();
+    /// let old_secret = client.api_key().unwrap().to_string();
+    /// let new_key = client.generate_api_key(Some("ci-2025-06")).await.unwrap();
+    /// let new_client = client.clone().with_api_key(new_key.api_key.unwrap());
+    /// let old_key = new_client
+    ///     .list_api_keys()
+    ///     .await
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .find(|key| {
+    ///         key.id != new_key.id
+    ///             && old_secret.starts_with(&key.prefix)
+    ///             && old_secret.ends_with(&key.suffix)
+    ///     });
+    /// if let Some(old_key) = old_key {
+    ///     new_client.delete_api_key(&old_key.id).await.unwrap();
+    /// }
+    /// # });
+    /// ```
+    pub async fn generate_api_key(&self, name: Option<&str>) -> Result<ApiKeyResponse, Error> {
+        info!("Generating API key {}", name.unwrap_or_default());
+        let api_key = apikey_generate_with_auth(&self.config, name).await?;
+        revealed_secret(&api_key)?;
+        Ok(api_key)
+    }
+
+    /// Revokes an API key by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key_id` - Database ID of the key, as returned by [`list_api_keys`](Self::list_api_keys)
+    ///
+    /// # Warning
+    ///
+    /// Revoking the key used by this client causes every subsequent request to fail.
+    pub async fn delete_api_key(&self, api_key_id: &str) -> Result<(), Error> {
+        info!("Deleting API key with ID: {}", api_key_id);
+        apikey_delete(&self.config, Some(api_key_id)).await?;
+        Ok(())
+    }
+
+    /// Lists the API keys of the authenticated team which have not been used recently.
+    ///
+    /// Keys which were never used are considered stale once they are older than `max_idle`.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_idle` - How long a key may go unused before it is considered stale
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use std::time::Duration;
+    /// use sindri::client::SindriClient;
+    ///
+    /// let client = SindriClient::default();
+    /// let ninety_days = Duration::from_secs(90 * 24 * 60 * 60);
+    /// for key in client.list_stale_api_keys(ninety_days).await.unwrap() {
+    ///     println!("Stale key {} ({}...{})", key.name, key.prefix, key.suffix);
+    /// }
+    /// # });
+    /// ```
+    pub async fn list_stale_api_keys(
+        &self,
+        max_idle: Duration,
+    ) -> Result<Vec<ApiKeyResponse>, Error> {
+        let max_idle = TimeDelta::from_std(max_idle)
+            .map_err(|e| Error::Validation(format!("Invalid idle duration: {}", e)))?;
+        let cutoff = Utc::now() - max_idle;
+        let keys = self.list_api_keys().await?;
+        Ok(keys
+            .into_iter()
+            .filter(|key| is_stale(key, cutoff))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    fn api_key(id: &str, date_created: &str, date_last_used: Option<&str>) -> ApiKeyResponse {
+        ApiKeyResponse {
+            id: id.to_string(),
+            date_created: date_created.to_string(),
+            date_last_used: date_last_used.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_is_stale() {
+        let cutoff = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+
+        let recent = api_key("1", "2024-01-01T00:00:00Z", Some("2025-04-01T00:00:00Z"));
+        assert!(!is_stale(&recent, cutoff));
+        let idle = api_key("2", "2024-01-01T00:00:00Z", Some("2025-01-01T00:00:00Z"));
+        assert!(is_stale(&idle, cutoff));
+
+        // Unused keys are judged by their creation date
+        let unused_old = api_key("3", "2024-01-01T00:00:00Z", None);
+        assert!(is_stale(&unused_old, cutoff));
+        let unused_new = api_key("4", "2025-05-01T00:00:00Z", None);
+        assert!(!is_stale(&unused_new, cutoff));
+
+        let unparseable = api_key("5", "yesterday", None);
+        assert!(!is_stale(&unparseable, cutoff));
+    }
+
+    #[test]
+    fn test_revealed_secret() {
+        let mut key = api_key("1", "2024-01-01T00:00:00Z", None);
+        let error = revealed_secret(&key).unwrap_err();
+        assert!(matches!(error, Error::UnexpectedResponse(_)));
+
+        key.api_key = Some("secret".to_string());
+        assert_eq!(revealed_secret(&key).unwrap(), "secret");
+    }
+
+    #[tokio::test]
+    async fn test_list_stale_api_keys() {
+        let mock_server = MockServer::start().await;
+        let now = Utc::now().to_rfc3339();
+        Mock::given(method("GET"))
+            .and(path("/api/v1/apikey/list"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vec![
+                api_key("fresh", "2024-01-01T00:00:00Z", Some(&now)),
+                api_key(
+                    "stale",
+                    "2024-01-01T00:00:00Z",
+                    Some("2024-02-01T00:00:00Z"),
+                ),
+            ]))
+            .mount(&mock_server)
+            .await;
+
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+        let stale = client
+            .list_stale_api_keys(Duration::from_secs(30 * 24 * 60 * 60))
+            .await
+            .unwrap();
+        let ids: Vec<_> = stale.iter().map(|key| key.id.as_str()).collect();
+        assert_eq!(ids, vec!["stale"]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_api_key() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/api/v1/apikey/key_123/delete"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+        client.delete_api_key("key_123").await.unwrap();
+    }
+}
@@ -0,0 +1,689 @@
+//! A blocking client for the Sindri API, for use in synchronous code.
+//!
+//! The [`SindriClient`] in this module mirrors the async [`crate::client::SindriClient`],
+//! running every request on a single runtime which it owns for its whole lifetime.
+//! Its methods return [`Error::BlockingInAsync`] rather than panicking when called from
+//! within an async runtime, where the async client should be used instead.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use sindri::{blocking::SindriClient, CircuitInfo};
+//!
+//! let client = SindriClient::default();
+//! let circuit = client
+//!     .create_circuit("path/to/circuit".to_string(), None, None)
+//!     .unwrap();
+//! let proof = client
+//!     .prove_circuit(circuit.id(), r#"{"a": 1, "b": 2}"#, None, None, None)
+//!     .unwrap();
+//! ```
+
+use std::{collections::HashMap, future::Future, path::Path, sync::Arc, time::Duration};
+
+use chrono::{DateTime, Utc};
+use futures::{Stream, StreamExt};
+use sindri_openapi::models::{
+    ApiKeyResponse, CircuitInfoResponse, ProjectInfoResponse, ProofInfoResponse, TeamDetail,
+    TeamMemberDetail,
+};
+use tokio::runtime::Runtime;
+
+use crate::{
+    client::{
+        AuthOptions, BatchOptions, CircuitListFilter, DedupeMode, JwtSession, PackageOptions,
+        PollingOptions, PollingStrategy, ProjectArchive, ProjectSettings,
+        SindriClient as AsyncClient, TeamSettings,
+    },
+    types::{
+        JobStatusUpdate, PackagePlan, ProofHistogram, ProofInput, ProofListInput,
+        SmartContractVerifier, UploadProgress,
+    },
+    CancellationToken, Error,
+};
+
+/// Returns an error if the current thread is driving an async runtime,
+/// where blocking on another runtime would panic.
+pub(crate) fn ensure_blocking_allowed() -> Result<(), Error> {
+    if tokio::runtime::Handle::try_current().is_ok() {
+        return Err(Error::BlockingInAsync);
+    }
+    Ok(())
+}
+
+/// A runtime which may be dropped from any context, including from within async code.
+#[derive(Debug)]
+struct OwnedRuntime(Option<Runtime>);
+
+impl Drop for OwnedRuntime {
+    fn drop(&mut self) {
+        if let Some(runtime) = self.0.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+/// A blocking version of the async [`SindriClient`](crate::client::SindriClient).
+///
+/// The client owns one runtime which is shared by all of its clones, so it should be
+/// created once and reused rather than created per call.
+#[derive(Debug, Clone)]
+pub struct SindriClient {
+    inner: AsyncClient,
+    runtime: Arc<OwnedRuntime>,
+}
+
+impl Default for SindriClient {
+    /// Creates a new blocking Sindri API client with default options.
+    ///
+    /// This is equivalent to calling `SindriClient::new(None, None)`.
+    fn default() -> Self {
+        Self::new(None, None)
+    }
+}
+
+impl SindriClient {
+    /// Creates a new blocking Sindri API client.
+    ///
+    /// See [`SindriClient::new`](crate::client::SindriClient::new) for details on the arguments.
+    pub fn new(auth_options: Option<AuthOptions>, polling_options: Option<PollingOptions>) -> Self {
+        Self::from_async(AsyncClient::new(auth_options, polling_options))
+            .expect("Could not build runtime")
+    }
+
+    /// Wraps an async client, e.g. one configured through a
+    /// [`SindriClientBuilder`](crate::client::SindriClientBuilder).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if the runtime cannot be created.
+    pub fn from_async(client: AsyncClient) -> Result<Self, Error> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()?;
+        Ok(Self {
+            inner: client,
+            runtime: Arc::new(OwnedRuntime(Some(runtime))),
+        })
+    }
+
+    /// Returns the underlying async client.
+    pub fn as_async(&self) -> &AsyncClient {
+        &self.inner
+    }
+
+    /// Runs a future to completion on the client's runtime.
+    fn block_on<F: Future>(&self, future: F) -> Result<F::Output, Error> {
+        ensure_blocking_allowed()?;
+        let runtime = self
+            .runtime
+            .0
+            .as_ref()
+            .expect("Runtime is only taken on drop");
+        Ok(runtime.block_on(future))
+    }
+
+    /// Turns a stream into an iterator which blocks on each item.
+    ///
+    /// The iterator ends after the first error, like the streams of the async client.
+    pub(crate) fn block_on_stream<'a, T: 'a>(
+        &'a self,
+        stream: impl Stream<Item = Result<T, Error>> + 'a,
+    ) -> impl Iterator<Item = Result<T, Error>> + 'a {
+        let mut stream = Box::pin(stream);
+        let mut finished = false;
+        std::iter::from_fn(move || {
+            if finished {
+                return None;
+            }
+            let item = self
+                .block_on(stream.next())
+                .unwrap_or_else(|e| Some(Err(e)));
+            finished = !matches!(item, Some(Ok(_)));
+            item
+        })
+    }
+
+    /// Returns the configured API key
+    pub fn api_key(&self) -> Option<&str> {
+        self.inner.api_key()
+    }
+
+    /// Returns the configured base URL for API requests
+    pub fn base_url(&self) -> &str {
+        self.inner.base_url()
+    }
+
+    /// Returns the polling options used while waiting on circuits and proofs
+    pub fn polling_options(&self) -> &PollingOptions {
+        &self.inner.polling_options
+    }
+
+    /// Sets the API key for this client.
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.inner = self.inner.with_api_key(api_key);
+        self
+    }
+
+    /// Sets the base URL for this client.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.inner = self.inner.with_base_url(base_url);
+        self
+    }
+
+    /// Sets the polling options for this client.
+    pub fn with_polling_options(mut self, polling_options: PollingOptions) -> Self {
+        self.inner.polling_options = polling_options;
+        self
+    }
+
+    /// Sets the polling interval for this client.
+    pub fn with_polling_interval(mut self, interval: Duration) -> Self {
+        self.inner = self.inner.with_polling_interval(interval);
+        self
+    }
+
+    /// Sets the polling strategy for this client.
+    pub fn with_polling_strategy(mut self, strategy: PollingStrategy) -> Self {
+        self.inner = self.inner.with_polling_strategy(strategy);
+        self
+    }
+
+    /// Sets the polling jitter for this client.
+    pub fn with_polling_jitter(mut self, jitter: f64) -> Self {
+        self.inner = self.inner.with_polling_jitter(jitter);
+        self
+    }
+
+    /// Sets the polling timeout for this client.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.inner = self.inner.with_timeout(timeout);
+        self
+    }
+
+    /// Removes the polling timeout for this client.
+    pub fn with_no_timeout(mut self) -> Self {
+        self.inner = self.inner.with_no_timeout();
+        self
+    }
+
+    /// Sets a cancellation token which stops this client from waiting on jobs.
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.inner = self.inner.with_cancellation_token(token);
+        self
+    }
+
+    /// Sets whether new circuits record the digest of their project in `meta`.
+    pub fn with_project_digest_meta(mut self, attach: bool) -> Self {
+        self.inner = self.inner.with_project_digest_meta(attach);
+        self
+    }
+
+    /// Sets whether deploys reuse an existing circuit built from an identical project.
+    pub fn with_dedupe(mut self, dedupe: DedupeMode) -> Self {
+        self.inner = self.inner.with_dedupe(dedupe);
+        self
+    }
+
+    /// Sets the options used when packaging project directories.
+    pub fn with_package_options(mut self, package_options: PackageOptions) -> Self {
+        self.inner = self.inner.with_package_options(package_options);
+        self
+    }
+
+    /// Sets a callback which reports the progress of project uploads.
+    pub fn with_upload_progress(
+        mut self,
+        callback: impl Fn(UploadProgress) + Send + Sync + 'static,
+    ) -> Self {
+        self.inner = self.inner.with_upload_progress(callback);
+        self
+    }
+
+    /// Blocking version of [`create_circuit`](AsyncClient::create_circuit).
+    pub fn create_circuit(
+        &self,
+        project: String,
+        tags: Option<Vec<String>>,
+        meta: Option<HashMap<String, String>>,
+    ) -> Result<CircuitInfoResponse, Error> {
+        self.block_on(self.inner.create_circuit(project, tags, meta))?
+    }
+
+    /// Blocking version of [`create_circuit_with_options`](AsyncClient::create_circuit_with_options).
+    pub fn create_circuit_with_options(
+        &self,
+        project: String,
+        tags: Option<Vec<String>>,
+        meta: Option<HashMap<String, String>>,
+        polling_options: PollingOptions,
+    ) -> Result<CircuitInfoResponse, Error> {
+        self.block_on(
+            self.inner
+                .create_circuit_with_options(project, tags, meta, polling_options),
+        )?
+    }
+
+    /// Blocking version of [`get_manifest_schema`](AsyncClient::get_manifest_schema).
+    pub fn get_manifest_schema(&self) -> Result<serde_json::Value, Error> {
+        self.block_on(self.inner.get_manifest_schema())
+    }
+
+    /// Blocking version of [`validate_manifest`](AsyncClient::validate_manifest).
+    pub fn validate_manifest(&self, project: impl AsRef<Path>) -> Result<(), Error> {
+        self.block_on(self.inner.validate_manifest(project))?
+    }
+
+    /// Blocking version of [`request_build`](AsyncClient::request_build).
+    pub fn request_build(
+        &self,
+        project: String,
+        tags: Option<Vec<String>>,
+        meta: Option<HashMap<String, String>>,
+    ) -> Result<CircuitInfoResponse, Error> {
+        self.block_on(self.inner.request_build(project, tags, meta))?
+    }
+
+    /// Blocking version of [`package_project`](AsyncClient::package_project).
+    pub fn package_project(&self, project: impl AsRef<Path>) -> Result<ProjectArchive, Error> {
+        self.block_on(self.inner.package_project(project))?
+    }
+
+    /// Blocking version of [`plan_package`](AsyncClient::plan_package).
+    pub fn plan_package(&self, dir: impl AsRef<Path>) -> Result<PackagePlan, Error> {
+        self.inner.plan_package(dir)
+    }
+
+    /// Blocking version of [`wait_for_circuit`](AsyncClient::wait_for_circuit).
+    pub fn wait_for_circuit(
+        &self,
+        circuit_id: &str,
+        polling_options: PollingOptions,
+    ) -> Result<CircuitInfoResponse, Error> {
+        self.block_on(self.inner.wait_for_circuit(circuit_id, polling_options))?
+    }
+
+    /// Blocking version of [`watch_circuit`](AsyncClient::watch_circuit),
+    /// which returns an iterator of status transitions.
+    pub fn watch_circuit(
+        &self,
+        circuit_id: &str,
+    ) -> impl Iterator<Item = Result<JobStatusUpdate, Error>> + '_ {
+        self.block_on_stream(self.inner.watch_circuit(circuit_id))
+    }
+
+    /// Blocking version of [`get_circuit`](AsyncClient::get_circuit).
+    pub fn get_circuit(
+        &self,
+        circuit_id: &str,
+        include_verification_key: Option<bool>,
+    ) -> Result<CircuitInfoResponse, Error> {
+        self.block_on(self.inner.get_circuit(circuit_id, include_verification_key))?
+    }
+
+    /// Blocking version of [`list_circuits`](AsyncClient::list_circuits).
+    pub fn list_circuits(
+        &self,
+        filter: CircuitListFilter,
+    ) -> Result<Vec<CircuitInfoResponse>, Error> {
+        self.block_on(self.inner.list_circuits(filter))?
+    }
+
+    /// Blocking version of [`clone_circuit`](AsyncClient::clone_circuit).
+    pub fn clone_circuit(&self, circuit_id: &str, download_path: String) -> Result<(), Error> {
+        self.block_on(self.inner.clone_circuit(circuit_id, download_path))?
+    }
+
+    /// Blocking version of [`delete_circuit`](AsyncClient::delete_circuit).
+    pub fn delete_circuit(&self, circuit_id: &str) -> Result<(), Error> {
+        self.block_on(self.inner.delete_circuit(circuit_id))?
+    }
+
+    /// Blocking version of [`list_projects`](AsyncClient::list_projects).
+    pub fn list_projects(&self) -> Result<Vec<ProjectInfoResponse>, Error> {
+        self.block_on(self.inner.list_projects())?
+    }
+
+    /// Blocking version of [`get_project`](AsyncClient::get_project).
+    pub fn get_project(&self, project_id: &str) -> Result<ProjectInfoResponse, Error> {
+        self.block_on(self.inner.get_project(project_id))?
+    }
+
+    /// Blocking version of [`update_project_settings`](AsyncClient::update_project_settings).
+    pub fn update_project_settings(
+        &self,
+        project_name: &str,
+        settings: ProjectSettings,
+    ) -> Result<ProjectInfoResponse, Error> {
+        self.block_on(self.inner.update_project_settings(project_name, settings))?
+    }
+
+    /// Blocking version of [`list_project_circuits`](AsyncClient::list_project_circuits).
+    pub fn list_project_circuits(
+        &self,
+        project_id: &str,
+    ) -> Result<Vec<CircuitInfoResponse>, Error> {
+        self.block_on(self.inner.list_project_circuits(project_id))?
+    }
+
+    /// Blocking version of [`delete_project`](AsyncClient::delete_project).
+    pub fn delete_project(&self, project_id: &str) -> Result<(), Error> {
+        self.block_on(self.inner.delete_project(project_id))?
+    }
+
+    /// Blocking version of [`create_team`](AsyncClient::create_team).
+    pub fn create_team(
+        &self,
+        slug: &str,
+        display_name: Option<String>,
+        description: Option<String>,
+    ) -> Result<TeamDetail, Error> {
+        self.block_on(self.inner.create_team(slug, display_name, description))?
+    }
+
+    /// Blocking version of [`get_team`](AsyncClient::get_team).
+    pub fn get_team(&self, team_slug: &str) -> Result<TeamDetail, Error> {
+        self.block_on(self.inner.get_team(team_slug))?
+    }
+
+    /// Blocking version of [`get_current_team`](AsyncClient::get_current_team).
+    pub fn get_current_team(&self) -> Result<TeamDetail, Error> {
+        self.block_on(self.inner.get_current_team())?
+    }
+
+    /// Blocking version of [`list_team_members`](AsyncClient::list_team_members).
+    pub fn list_team_members(&self, team_slug: &str) -> Result<Vec<TeamMemberDetail>, Error> {
+        self.block_on(self.inner.list_team_members(team_slug))?
+    }
+
+    /// Blocking version of [`invite_team_member`](AsyncClient::invite_team_member).
+    pub fn invite_team_member(&self, email: &str) -> Result<(), Error> {
+        self.block_on(self.inner.invite_team_member(email))?
+    }
+
+    /// Blocking version of [`remove_team_member`](AsyncClient::remove_team_member).
+    pub fn remove_team_member(&self, team_slug: &str, username: &str) -> Result<(), Error> {
+        self.block_on(self.inner.remove_team_member(team_slug, username))?
+    }
+
+    /// Blocking version of [`update_team_settings`](AsyncClient::update_team_settings).
+    pub fn update_team_settings(&self, settings: TeamSettings) -> Result<TeamDetail, Error> {
+        self.block_on(self.inner.update_team_settings(settings))?
+    }
+
+    /// Blocking version of [`upload_team_avatar`](AsyncClient::upload_team_avatar).
+    pub fn upload_team_avatar(&self, path: impl AsRef<Path>) -> Result<TeamDetail, Error> {
+        self.block_on(self.inner.upload_team_avatar(path))?
+    }
+
+    /// Blocking version of [`list_api_keys`](AsyncClient::list_api_keys).
+    pub fn list_api_keys(&self) -> Result<Vec<ApiKeyResponse>, Error> {
+        self.block_on(self.inner.list_api_keys())?
+    }
+
+    /// Blocking version of [`generate_api_key`](AsyncClient::generate_api_key).
+    pub fn generate_api_key(&self, name: Option<&str>) -> Result<ApiKeyResponse, Error> {
+        self.block_on(self.inner.generate_api_key(name))?
+    }
+
+    /// Blocking version of [`delete_api_key`](AsyncClient::delete_api_key).
+    pub fn delete_api_key(&self, api_key_id: &str) -> Result<(), Error> {
+        self.block_on(self.inner.delete_api_key(api_key_id))?
+    }
+
+    /// Blocking version of [`list_stale_api_keys`](AsyncClient::list_stale_api_keys).
+    pub fn list_stale_api_keys(&self, max_idle: Duration) -> Result<Vec<ApiKeyResponse>, Error> {
+        self.block_on(self.inner.list_stale_api_keys(max_idle))?
+    }
+
+    /// Blocking version of [`get_proof_histogram`](AsyncClient::get_proof_histogram).
+    pub fn get_proof_histogram(
+        &self,
+        start_time: DateTime<Utc>,
+        end_time: Option<DateTime<Utc>>,
+        bin_size: Option<Duration>,
+    ) -> Result<ProofHistogram, Error> {
+        self.block_on(
+            self.inner
+                .get_proof_histogram(start_time, end_time, bin_size),
+        )?
+    }
+
+    /// Blocking version of [`get_smart_contract_verifier`](AsyncClient::get_smart_contract_verifier).
+    pub fn get_smart_contract_verifier(
+        &self,
+        circuit_id: &str,
+    ) -> Result<SmartContractVerifier, Error> {
+        self.block_on(self.inner.get_smart_contract_verifier(circuit_id))?
+    }
+
+    /// Blocking version of [`prove_circuit`](AsyncClient::prove_circuit).
+    pub fn prove_circuit(
+        &self,
+        circuit_id: &str,
+        proof_input: impl Into<ProofInput>,
+        meta: Option<HashMap<String, String>>,
+        verify: Option<bool>,
+        prover_implementation: Option<String>,
+    ) -> Result<ProofInfoResponse, Error> {
+        self.block_on(self.inner.prove_circuit(
+            circuit_id,
+            proof_input,
+            meta,
+            verify,
+            prover_implementation,
+        ))?
+    }
+
+    /// Blocking version of [`prove_circuit_with_options`](AsyncClient::prove_circuit_with_options).
+    pub fn prove_circuit_with_options(
+        &self,
+        circuit_id: &str,
+        proof_input: impl Into<ProofInput>,
+        meta: Option<HashMap<String, String>>,
+        verify: Option<bool>,
+        prover_implementation: Option<String>,
+        polling_options: PollingOptions,
+    ) -> Result<ProofInfoResponse, Error> {
+        self.block_on(self.inner.prove_circuit_with_options(
+            circuit_id,
+            proof_input,
+            meta,
+            verify,
+            prover_implementation,
+            polling_options,
+        ))?
+    }
+
+    /// Blocking version of [`request_proof`](AsyncClient::request_proof).
+    pub fn request_proof(
+        &self,
+        circuit_id: &str,
+        proof_input: impl Into<ProofInput>,
+        meta: Option<HashMap<String, String>>,
+        verify: Option<bool>,
+        prover_implementation: Option<String>,
+    ) -> Result<ProofInfoResponse, Error> {
+        self.block_on(self.inner.request_proof(
+            circuit_id,
+            proof_input,
+            meta,
+            verify,
+            prover_implementation,
+        ))?
+    }
+
+    /// Blocking version of [`prove_batch`](AsyncClient::prove_batch).
+    pub fn prove_batch<I: Into<ProofInput>>(
+        &self,
+        circuit_id: &str,
+        inputs: impl IntoIterator<Item = I>,
+        options: BatchOptions,
+    ) -> Result<Vec<Result<ProofInfoResponse, Error>>, Error> {
+        self.block_on(self.inner.prove_batch(circuit_id, inputs, options))
+    }
+
+    /// Blocking version of [`wait_for_proof`](AsyncClient::wait_for_proof).
+    pub fn wait_for_proof(
+        &self,
+        proof_id: &str,
+        polling_options: PollingOptions,
+    ) -> Result<ProofInfoResponse, Error> {
+        self.block_on(self.inner.wait_for_proof(proof_id, polling_options))?
+    }
+
+    /// Blocking version of [`watch_proof`](AsyncClient::watch_proof),
+    /// which returns an iterator of status transitions.
+    pub fn watch_proof(
+        &self,
+        proof_id: &str,
+    ) -> impl Iterator<Item = Result<JobStatusUpdate, Error>> + '_ {
+        self.block_on_stream(self.inner.watch_proof(proof_id))
+    }
+
+    /// Blocking version of [`get_proof`](AsyncClient::get_proof).
+    pub fn get_proof(
+        &self,
+        proof_id: &str,
+        include_proof: Option<bool>,
+        include_public: Option<bool>,
+        include_verification_key: Option<bool>,
+    ) -> Result<ProofInfoResponse, Error> {
+        self.block_on(self.inner.get_proof(
+            proof_id,
+            include_proof,
+            include_public,
+            include_verification_key,
+        ))?
+    }
+
+    /// Blocking version of [`proofs`](AsyncClient::proofs),
+    /// which returns an iterator fetching pages as it advances.
+    pub fn proofs(
+        &self,
+        input: ProofListInput,
+    ) -> impl Iterator<Item = Result<ProofInfoResponse, Error>> + '_ {
+        self.block_on_stream(self.inner.proofs(input))
+    }
+
+    /// Blocking version of [`circuit_proofs`](AsyncClient::circuit_proofs),
+    /// which returns an iterator fetching pages as it advances.
+    pub fn circuit_proofs(
+        &self,
+        circuit_id: &str,
+    ) -> impl Iterator<Item = Result<ProofInfoResponse, Error>> + '_ {
+        self.block_on_stream(self.inner.circuit_proofs(circuit_id))
+    }
+
+    /// Blocking version of [`project_proofs`](AsyncClient::project_proofs),
+    /// which returns an iterator fetching pages as it advances.
+    pub fn project_proofs(
+        &self,
+        project_id: &str,
+    ) -> impl Iterator<Item = Result<ProofInfoResponse, Error>> + '_ {
+        self.block_on_stream(self.inner.project_proofs(project_id))
+    }
+
+    /// Blocking version of [`delete_proof`](AsyncClient::delete_proof).
+    pub fn delete_proof(&self, proof_id: &str) -> Result<(), Error> {
+        self.block_on(self.inner.delete_proof(proof_id))?
+    }
+
+    /// Blocking version of [`jwt_token_generate`](AsyncClient::jwt_token_generate).
+    pub fn jwt_token_generate(&self, username: &str, password: &str) -> Result<String, Error> {
+        self.block_on(self.inner.jwt_token_generate(username, password))?
+    }
+
+    /// Blocking version of [`jwt_session_generate`](AsyncClient::jwt_session_generate).
+    pub fn jwt_session_generate(
+        &self,
+        username: &str,
+        password: &str,
+    ) -> Result<JwtSession, Error> {
+        self.block_on(self.inner.jwt_session_generate(username, password))?
+    }
+
+    /// Blocking version of [`teams_jwt_auth`](AsyncClient::teams_jwt_auth).
+    pub fn teams_jwt_auth(&self, token: &str) -> Result<Vec<TeamDetail>, Error> {
+        self.block_on(self.inner.teams_jwt_auth(token))?
+    }
+
+    /// Blocking version of [`api_key_select_team`](AsyncClient::api_key_select_team).
+    pub fn api_key_select_team(
+        &self,
+        username: &str,
+        password: &str,
+        key_name: &str,
+        team_id: &str,
+    ) -> Result<String, Error> {
+        self.block_on(
+            self.inner
+                .api_key_select_team(username, password, key_name, team_id),
+        )?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sindri_openapi::models::JobStatus;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    fn mock_server(runtime: &Runtime) -> MockServer {
+        runtime.block_on(async {
+            let mock_server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path("/api/v1/proof/test_proof_123/detail"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(ProofInfoResponse {
+                    proof_id: "test_proof_123".to_string(),
+                    status: JobStatus::Ready,
+                    ..Default::default()
+                }))
+                .mount(&mock_server)
+                .await;
+            Mock::given(method("DELETE"))
+                .and(path("/api/v1/proof/test_proof_123/delete"))
+                .respond_with(
+                    ResponseTemplate::new(200).set_body_json(serde_json::json!({"success": true})),
+                )
+                .mount(&mock_server)
+                .await;
+            mock_server
+        })
+    }
+
+    #[test]
+    fn test_blocking_client_reuses_runtime() {
+        // The mock server runs on its own runtime, separate from the client's
+        let server_runtime = Runtime::new().unwrap();
+        let mock_server = mock_server(&server_runtime);
+
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+        let proof = client
+            .get_proof("test_proof_123", None, None, None)
+            .unwrap();
+        assert_eq!(proof.status, JobStatus::Ready);
+        client.delete_proof("test_proof_123").unwrap();
+
+        let updates: Vec<_> = client.clone().watch_proof("test_proof_123").collect();
+        assert_eq!(updates.len(), 1);
+        assert!(updates[0].as_ref().unwrap().is_finished());
+    }
+
+    #[tokio::test]
+    async fn test_blocking_client_in_async_context() {
+        let client = SindriClient::default();
+        let result = client.get_proof("test_proof_123", None, None, None);
+        assert!(matches!(result, Err(Error::BlockingInAsync)));
+
+        // Iterators report the error once rather than looping forever
+        let updates: Vec<_> = client.watch_proof("test_proof_123").collect();
+        assert_eq!(updates.len(), 1);
+
+        // Dropping the client (and its runtime) here must not panic
+        drop(client);
+    }
+}
@@ -0,0 +1,255 @@
+//! These methods list the circuits visible to the authenticated team.
+
+use chrono::{DateTime, Utc};
+use sindri_openapi::{
+    apis::circuits_api::circuit_list,
+    models::{CircuitInfoResponse, CircuitType, JobStatus},
+};
+use tracing::debug;
+
+use crate::{client::SindriClient, types::CircuitInfo, utils::parse_api_datetime, Error};
+
+/// Criteria for selecting circuits with [`SindriClient::list_circuits`].
+///
+/// Every field is optional, and a circuit must satisfy all of the fields which are set.
+///
+/// # Fields
+///
+/// * `project_name` - Only circuits belonging to this project
+/// * `tags` - Only circuits carrying every one of these tags
+/// * `status` - Only circuits with this status
+/// * `circuit_type` - Only circuits of this framework (e.g. Noir, SP1)
+/// * `created_after` - Only circuits created at or after this time
+/// * `created_before` - Only circuits created strictly before this time
+///
+/// # Examples
+///
+/// ```
+/// use sindri::{client::CircuitListFilter, CircuitType, JobStatus};
+///
+/// let filter = CircuitListFilter {
+///     project_name: Some("my-project".to_string()),
+///     status: Some(JobStatus::Ready),
+///     circuit_type: Some(CircuitType::Noir),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CircuitListFilter {
+    pub project_name: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub status: Option<JobStatus>,
+    pub circuit_type: Option<CircuitType>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+}
+
+impl CircuitListFilter {
+    /// Returns whether the circuit satisfies every criterion of the filter.
+    ///
+    /// Circuits whose creation date cannot be parsed are excluded by date criteria.
+    pub fn matches(&self, circuit: &CircuitInfoResponse) -> bool {
+        if let Some(project_name) = &self.project_name {
+            if circuit.project_name() != project_name {
+                return false;
+            }
+        }
+        if let Some(tags) = &self.tags {
+            if !tags.iter().all(|tag| circuit.tags().contains(tag)) {
+                return false;
+            }
+        }
+        if let Some(status) = &self.status {
+            if circuit.status() != status {
+                return false;
+            }
+        }
+        if let Some(circuit_type) = self.circuit_type {
+            if circuit.circuit_type() != circuit_type {
+                return false;
+            }
+        }
+        if self.created_after.is_some() || self.created_before.is_some() {
+            let Some(date_created) = parse_api_datetime(circuit.date_created()) else {
+                return false;
+            };
+            if self.created_after.is_some_and(|after| date_created < after) {
+                return false;
+            }
+            if self
+                .created_before
+                .is_some_and(|before| date_created >= before)
+            {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl SindriClient {
+    /// Lists the circuits of the authenticated team which satisfy a filter.
+    ///
+    /// The Sindri API returns every circuit of the team, so the filter is applied
+    /// client-side after a single request.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Criteria the returned circuits must satisfy
+    ///
+    /// # Returns
+    ///
+    /// Returns the matching circuits in the order they were returned by the API.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use sindri::{client::{CircuitListFilter, SindriClient}, CircuitInfo, JobStatus};
+    ///
+    /// let client = SindriClient::default();
+    /// let filter = CircuitListFilter {
+    ///     tags: Some(vec!["latest".to_string()]),
+    ///     status: Some(JobStatus::Ready),
+    ///     ..Default::default()
+    /// };
+    /// for circuit in client.list_circuits(filter).await.unwrap() {
+    ///     println!("{} {}", circuit.id(), circuit.project_name());
+    /// }
+    /// # });
+    /// ```
+    pub async fn list_circuits(
+        &self,
+        filter: CircuitListFilter,
+    ) -> Result<Vec<CircuitInfoResponse>, Error> {
+        let circuits = circuit_list(&self.config).await?;
+        let total = circuits.len();
+        let circuits: Vec<_> = circuits
+            .into_iter()
+            .filter(|circuit| filter.matches(circuit))
+            .collect();
+        debug!(
+            "{} of {} circuits matched the filter",
+            circuits.len(),
+            total
+        );
+        Ok(circuits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NoirCircuitInfoResponse, Sp1CircuitInfoResponse};
+    use chrono::TimeZone;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    fn noir_circuit(
+        id: &str,
+        project_name: &str,
+        tags: &[&str],
+        date: &str,
+    ) -> CircuitInfoResponse {
+        CircuitInfoResponse::Noir(Box::new(NoirCircuitInfoResponse {
+            circuit_id: id.to_string(),
+            project_name: project_name.to_string(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            date_created: date.to_string(),
+            status: JobStatus::Ready,
+            ..Default::default()
+        }))
+    }
+
+    fn sp1_circuit(id: &str, status: JobStatus) -> CircuitInfoResponse {
+        CircuitInfoResponse::Sp1(Box::new(Sp1CircuitInfoResponse {
+            circuit_id: id.to_string(),
+            project_name: "zkvm".to_string(),
+            date_created: "2025-03-01T12:00:00Z".to_string(),
+            status,
+            ..Default::default()
+        }))
+    }
+
+    #[test]
+    fn test_filter_matches() {
+        let circuit = noir_circuit("1", "demo", &["latest", "v1"], "2025-02-01T00:00:00Z");
+
+        assert!(CircuitListFilter::default().matches(&circuit));
+        assert!(CircuitListFilter {
+            project_name: Some("demo".to_string()),
+            tags: Some(vec!["v1".to_string()]),
+            circuit_type: Some(CircuitType::Noir),
+            ..Default::default()
+        }
+        .matches(&circuit));
+        assert!(!CircuitListFilter {
+            tags: Some(vec!["v1".to_string(), "v2".to_string()]),
+            ..Default::default()
+        }
+        .matches(&circuit));
+        assert!(!CircuitListFilter {
+            status: Some(JobStatus::Failed),
+            ..Default::default()
+        }
+        .matches(&circuit));
+    }
+
+    #[test]
+    fn test_filter_date_range() {
+        let circuit = noir_circuit("1", "demo", &[], "2025-02-01T00:00:00Z");
+        let february = Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap();
+        let march = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+
+        let in_range = CircuitListFilter {
+            created_after: Some(february),
+            created_before: Some(march),
+            ..Default::default()
+        };
+        assert!(in_range.matches(&circuit));
+        let before_range = CircuitListFilter {
+            created_before: Some(february),
+            ..Default::default()
+        };
+        assert!(!before_range.matches(&circuit));
+
+        // Circuits without a parseable date never satisfy a date range
+        let undated = noir_circuit("2", "demo", &[], "not a date");
+        assert!(!in_range.matches(&undated));
+        assert!(CircuitListFilter::default().matches(&undated));
+    }
+
+    #[tokio::test]
+    async fn test_list_circuits() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/circuit/list"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vec![
+                noir_circuit("noir_1", "demo", &["latest"], "2025-01-01T00:00:00Z"),
+                sp1_circuit("sp1_1", JobStatus::Ready),
+                sp1_circuit("sp1_2", JobStatus::Failed),
+            ]))
+            .mount(&mock_server)
+            .await;
+
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+        let all = client
+            .list_circuits(CircuitListFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(all.len(), 3);
+
+        let ready_sp1 = client
+            .list_circuits(CircuitListFilter {
+                status: Some(JobStatus::Ready),
+                circuit_type: Some(CircuitType::Sp1),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let ids: Vec<_> = ready_sp1.iter().map(|circuit| circuit.id()).collect();
+        assert_eq!(ids, vec!["sp1_1"]);
+    }
+}
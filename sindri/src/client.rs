@@ -1,34 +1,46 @@
 //! # The primary module for interacting with Sindri's API.
 
-use std::{collections::HashMap, fs::File, io::Write, time::Duration};
+use std::{collections::HashMap, fs::File, io::Write, sync::Arc, time::Duration};
 
 use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest_middleware::Middleware;
+use reqwest_retry::policies::ExponentialBackoffTimed;
 use sindri_openapi::{
     apis::{
-        circuit_download, circuit_status,
-        circuits_api::{circuit_delete, circuit_detail, CircuitDetailError},
+        circuit_download,
+        circuits_api::{circuit_delete, circuit_detail},
         configuration::Configuration,
-        proof_status,
-        proofs_api::{proof_delete, proof_detail, ProofDetailError},
-        Error,
+        proofs_api::{proof_delete, proof_detail},
     },
-    models::{CircuitInfoResponse, JobStatus, ProofInfoResponse},
+    models::{CircuitInfoResponse, ProofInfoResponse},
 };
-use tracing::{debug, info, warn};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info};
 
 use crate::{
+    blocking::ensure_blocking_allowed,
     custom_middleware::{
-        retry_client, HeaderDeduplicatorMiddleware, LoggingMiddleware,
-        ZstdRequestCompressionMiddleware,
+        retry_client_with_bounds, retry_policy_with_bounds, HeaderDeduplicatorMiddleware,
+        JwtSessionMiddleware, LoggingMiddleware, RetryBufferedMiddleware,
+        ZstdRequestCompressionMiddleware, DEFAULT_RETRY_DURATION, DEFAULT_RETRY_MAX_INTERVAL,
+        DEFAULT_RETRY_MIN_INTERVAL,
     },
-    types::{CircuitInfo, ProofInput},
+    types::{CircuitInfo, ProofInput, UploadProgress},
+    upload::UploadProgressCallback,
+    Error,
 };
 
 #[cfg(any(feature = "record", feature = "replay"))]
 use crate::custom_middleware::vcr_middleware;
 
-#[cfg(feature = "rich-terminal")]
-use crate::utils::ClockProgressBar;
+pub use crate::{
+    circuits::CircuitListFilter,
+    job_batch::BatchOptions,
+    jwt::JwtSession,
+    projects::ProjectSettings,
+    teams::TeamSettings,
+    utils::{format_size, ProjectArchive, MAX_PROJECT_SIZE, PROJECT_DIGEST_META_KEY},
+};
 
 /// Configuration options for authenticating with the Sindri API.
 ///
@@ -40,7 +52,7 @@ use crate::utils::ClockProgressBar;
 ///
 /// * `api_key` - Optional API key for authentication. If not provided, falls back to `SINDRI_API_KEY` environment variable
 /// * `base_url` - Optional base URL for API requests. Should be left as `None` except for internal development purposes.
-///                If not provided, falls back to `SINDRI_BASE_URL` environment variable, then to the default production URL
+///   If not provided, falls back to `SINDRI_BASE_URL` environment variable, then to the default production URL
 ///
 /// # Examples
 ///
@@ -68,8 +80,15 @@ pub struct AuthOptions {
 ///
 /// # Fields
 ///
-/// * `interval` - Duration to wait between API status checks (default: 1 second)
+/// * `interval` - Duration to wait between API status checks (default: 1 second).
+///   For strategies other than [`PollingStrategy::Fixed`], this is the shortest wait.
+/// * `strategy` - How the wait between status checks evolves over time (default: fixed)
+/// * `jitter` - Fraction by which each wait is randomly lengthened or shortened, between
+///   `0.0` and `1.0` (default: no jitter). Spreads out requests when polling many jobs at once.
 /// * `timeout` - Optional maximum duration to wait for operation completion (default: 10 minutes)
+/// * `cancellation_token` - Optional token which stops the wait early when cancelled (default: none).
+///   Cancelling only stops the client from waiting; the job keeps running on Sindri and can be
+///   looked up again with the ID carried by [`Error::Cancelled`].
 ///
 /// # Examples
 ///
@@ -81,6 +100,7 @@ pub struct AuthOptions {
 /// let polling = PollingOptions {
 ///     interval: Duration::from_secs(5),
 ///     timeout: Some(Duration::from_secs(1800)), // 30 minutes
+///     ..Default::default()
 /// };
 /// let client = SindriClient::new(None, Some(polling));
 ///
@@ -89,29 +109,294 @@ pub struct AuthOptions {
 /// client.polling_options.timeout = Some(Duration::from_secs(1800));
 /// ```
 ///
+/// Long-running jobs such as zkVM proofs can back off instead of polling every second:
+///
+/// ```
+/// use sindri::client::{PollingOptions, PollingStrategy};
+/// use std::time::Duration;
+///
+/// let polling = PollingOptions {
+///     strategy: PollingStrategy::Exponential {
+///         multiplier: 2.0,
+///         max_interval: Duration::from_secs(30),
+///     },
+///     jitter: 0.1,
+///     timeout: None,
+///     ..Default::default()
+/// };
+/// ```
+///
 /// The default values are suitable for most use cases and only need to be adjusted
 /// when working with circuits or proofs that are known to require longer processing times.
 #[derive(Debug, Clone)]
 pub struct PollingOptions {
     pub interval: Duration,
+    pub strategy: PollingStrategy,
+    pub jitter: f64,
     pub timeout: Option<Duration>,
+    pub cancellation_token: Option<CancellationToken>,
 }
 impl Default for PollingOptions {
     fn default() -> Self {
         Self {
             interval: Duration::from_secs(1),
+            strategy: PollingStrategy::Fixed,
+            jitter: 0.0,
             timeout: Some(Duration::from_secs(60 * 10)),
+            cancellation_token: None,
+        }
+    }
+}
+
+impl PollingOptions {
+    /// Returns how long to wait before the next status check.
+    ///
+    /// * `attempt` - Number of status checks made so far, starting from one
+    /// * `job_age_sec` - Time the job has spent queued and computing so far, if known
+    pub(crate) fn next_interval(&self, attempt: u32, job_age_sec: Option<f64>) -> Duration {
+        let interval = match &self.strategy {
+            PollingStrategy::Fixed => self.interval,
+            PollingStrategy::Exponential {
+                multiplier,
+                max_interval,
+            } => {
+                let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+                let secs = self.interval.as_secs_f64() * multiplier.max(1.0).powi(exponent);
+                Duration::try_from_secs_f64(secs)
+                    .unwrap_or(*max_interval)
+                    .min(*max_interval)
+            }
+            PollingStrategy::Adaptive {
+                fraction,
+                max_interval,
+            } => {
+                let secs = job_age_sec.unwrap_or_default() * fraction.max(0.0);
+                Duration::try_from_secs_f64(secs)
+                    .unwrap_or(*max_interval)
+                    .clamp(self.interval, (*max_interval).max(self.interval))
+            }
+        };
+
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        if jitter > 0.0 {
+            interval.mul_f64(rand::random_range(1.0 - jitter..=1.0 + jitter))
+        } else {
+            interval
+        }
+    }
+}
+
+/// Strategies for spacing out status checks while waiting on a job.
+///
+/// All strategies start from [`PollingOptions::interval`].
+///
+/// # Variants
+///
+/// * `Fixed` - Wait `interval` between every status check
+/// * `Exponential` - Multiply the wait by `multiplier` after every status check,
+///   up to `max_interval`
+/// * `Adaptive` - Wait a `fraction` of the time the job has already spent queued and computing
+///   (as reported by `queue_time_sec` and `compute_time_sec`), between `interval` and
+///   `max_interval`. Short jobs are checked often, while long jobs are checked rarely.
+///
+/// # Examples
+///
+/// ```
+/// use sindri::client::{PollingStrategy, SindriClient};
+/// use std::time::Duration;
+///
+/// // After 10 minutes of proving, the status is checked once per minute
+/// let client = SindriClient::default().with_polling_strategy(PollingStrategy::Adaptive {
+///     fraction: 0.1,
+///     max_interval: Duration::from_secs(60),
+/// });
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum PollingStrategy {
+    #[default]
+    Fixed,
+    Exponential {
+        multiplier: f64,
+        max_interval: Duration,
+    },
+    Adaptive {
+        fraction: f64,
+        max_interval: Duration,
+    },
+}
+
+/// Options controlling how projects are deployed by [`SindriClient::create_circuit`]
+/// and [`SindriClient::request_build`].
+///
+/// # Fields
+///
+/// * `attach_project_digest` - Whether to record the digest of the project (see
+///   [`ProjectArchive::digest`]) in the `meta` of new circuits under
+///   [`PROJECT_DIGEST_META_KEY`], so that each circuit can be traced back to the exact
+///   source tree that produced it. A digest supplied in `meta` by the caller is kept.
+/// * `dedupe` - Whether to reuse an existing circuit built from an identical project instead
+///   of deploying it again. The digest is always attached to new circuits when enabled.
+///
+/// # Examples
+///
+/// ```
+/// use sindri::client::{DedupeMode, DeployOptions, SindriClient};
+///
+/// // Skip the compile when CI redeploys an unchanged project
+/// let client = SindriClient::builder()
+///     .deploy_options(DeployOptions {
+///         dedupe: DedupeMode::ReuseTagged,
+///         ..Default::default()
+///     })
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DeployOptions {
+    pub attach_project_digest: bool,
+    pub dedupe: DedupeMode,
+}
+
+impl DeployOptions {
+    /// Returns whether new circuits record the digest of their project in `meta`.
+    pub(crate) fn attaches_project_digest(&self) -> bool {
+        self.attach_project_digest || self.dedupe != DedupeMode::Off
+    }
+}
+
+/// How deploys treat projects which are identical to an existing circuit.
+///
+/// Circuits are identified by the project digest recorded in their `meta` (see
+/// [`DeployOptions::attach_project_digest`]), so only circuits deployed with the digest
+/// attached can be reused. Only `Ready` circuits of the same project are considered, and the
+/// most recently created one is reused.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DedupeMode {
+    /// Always deploy the project.
+    #[default]
+    Off,
+    /// Reuse an identical circuit regardless of its tags, so the requested tags are not applied.
+    Reuse,
+    /// Reuse an identical circuit only if it already carries every requested tag. Otherwise
+    /// the project is deployed again so that the tags are applied, since tags cannot be added
+    /// to an existing circuit.
+    ReuseTagged,
+}
+
+/// Options controlling which files are packaged when deploying a project directory with
+/// [`SindriClient::create_circuit`] and [`SindriClient::request_build`].
+///
+/// By default, hidden files and files matched by a `.sindriignore`, `.ignore` or git ignore
+/// file are left out, and symbolic links to files are followed while links to directories are
+/// left out. `.git` directories are never packaged.
+///
+/// The options apply to every project packaged by the client. To package a single project
+/// differently, use a clone of the client with [`SindriClient::with_package_options`].
+///
+/// # Fields
+///
+/// * `include_hidden` - Whether to package hidden files and directories, e.g. `.cargo/config.toml`.
+///   This also packages files such as `.env` which commonly hold secrets, so they should be
+///   listed in `exclude` or an ignore file.
+/// * `respect_gitignore` - Whether to honor `.gitignore` files, `.git/info/exclude` and the global
+///   git excludes file. `.sindriignore` and `.ignore` files are always honored.
+/// * `symlinks` - How symbolic links are packaged (see [`SymlinkMode`])
+/// * `include` - Extra patterns, in the `.gitignore` syntax, of paths to package even if they are
+///   hidden or ignored
+/// * `exclude` - Extra patterns, in the `.gitignore` syntax, of paths to leave out. These take
+///   precedence over every other rule, including `include`.
+/// * `max_project_size` - Largest compressed project archive in bytes which may be uploaded
+///   (default: [`MAX_PROJECT_SIZE`])
+///
+/// # Examples
+///
+/// ```
+/// use sindri::client::{PackageOptions, SindriClient, SymlinkMode};
+///
+/// // Package the cargo configuration of a guest program and a symlinked shared library
+/// let client = SindriClient::builder()
+///     .package_options(PackageOptions {
+///         include: vec![".cargo/".to_string()],
+///         exclude: vec!["*.log".to_string()],
+///         symlinks: SymlinkMode::Follow,
+///         ..Default::default()
+///     })
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct PackageOptions {
+    pub include_hidden: bool,
+    pub respect_gitignore: bool,
+    pub symlinks: SymlinkMode,
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    pub max_project_size: u64,
+}
+
+impl Default for PackageOptions {
+    fn default() -> Self {
+        Self {
+            include_hidden: false,
+            respect_gitignore: true,
+            symlinks: SymlinkMode::default(),
+            include: Vec::new(),
+            exclude: Vec::new(),
+            max_project_size: MAX_PROJECT_SIZE,
         }
     }
 }
 
+/// How symbolic links within a project directory are packaged.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SymlinkMode {
+    /// Package the file which a link points to in place of the link, and leave out links to
+    /// directories and broken links.
+    #[default]
+    FollowFiles,
+    /// Package the file or directory which the link points to in place of the link.
+    Follow,
+    /// Package the link itself, which keeps pointing to the same target once extracted.
+    /// Packaging fails if a link is absolute or points outside of the project directory.
+    Preserve,
+    /// Fail packaging if the project contains a symbolic link.
+    Error,
+}
+
 /// The [`SindriClient`] struct encapsulates all the necessary methods and properties
 ///  required to communicate effectively with the Sindri API, handling tasks
 ///  like uploads of circuits or guest code and proof generation.
-#[derive(Debug)]
+///
+/// The client is cheap to clone: clones share the same connection pool and middleware stack,
+/// so a single client can be handed to many tasks without wrapping it in an `Arc`.
+///
+/// # Examples
+///
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// use sindri::client::SindriClient;
+///
+/// let client = SindriClient::default();
+/// let handles: Vec<_> = ["proof-id-1", "proof-id-2"]
+///     .into_iter()
+///     .map(|proof_id| {
+///         let client = client.clone();
+///         tokio::spawn(async move { client.get_proof(proof_id, None, None, None).await })
+///     })
+///     .collect();
+/// for handle in handles {
+///     println!("{:?}", handle.await.unwrap());
+/// }
+/// # });
+/// ```
+#[derive(Debug, Clone)]
 pub struct SindriClient {
     pub(crate) config: Configuration,
     pub polling_options: PollingOptions,
+    pub deploy_options: DeployOptions,
+    pub package_options: PackageOptions,
+    pub(crate) upload_retry_policy: ExponentialBackoffTimed,
+    pub(crate) upload_progress: Option<UploadProgressCallback>,
 }
 
 impl Default for SindriClient {
@@ -124,26 +409,195 @@ impl Default for SindriClient {
     }
 }
 
-impl SindriClient {
-    /// Creates a new Sindri API client.
-    ///
-    /// # Arguments
+/// A builder for a [`SindriClient`] with a customized HTTP stack.
+///
+/// Every request sent by the client passes through a stack of middleware which deduplicates
+/// headers, logs requests and responses, retries transient errors, and compresses large request
+/// bodies. The builder configures the underlying HTTP client and the retry policy, and can append
+/// extra [`Middleware`] layers (e.g. for metrics) which run after the built-in ones.
+///
+/// Options which are not set keep the same defaults as [`SindriClient::new`].
+///
+/// # Examples
+///
+/// ```
+/// use sindri::client::{AuthOptions, SindriClient};
+/// use std::time::Duration;
+///
+/// let client = SindriClient::builder()
+///     .auth_options(AuthOptions {
+///         api_key: Some("my_api_key".to_string()),
+///         base_url: None,
+///     })
+///     .request_timeout(Duration::from_secs(120))
+///     .connect_timeout(Duration::from_secs(10))
+///     .proxy(reqwest::Proxy::https("http://proxy.internal:3128").unwrap())
+///     .retry_budget(Duration::from_secs(300))
+///     .retry_bounds(Duration::from_millis(500), Duration::from_secs(30))
+///     .build()
+///     .unwrap();
+/// ```
+pub struct SindriClientBuilder {
+    auth_options: AuthOptions,
+    jwt_session: Option<JwtSession>,
+    polling_options: PollingOptions,
+    deploy_options: DeployOptions,
+    package_options: PackageOptions,
+    request_timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    proxies: Vec<reqwest::Proxy>,
+    root_certificates: Vec<reqwest::Certificate>,
+    user_agent: Option<String>,
+    retry_budget: Duration,
+    retry_min_interval: Duration,
+    retry_max_interval: Duration,
+    middleware: Vec<Arc<dyn Middleware>>,
+}
+
+impl Default for SindriClientBuilder {
+    fn default() -> Self {
+        Self {
+            auth_options: AuthOptions::default(),
+            jwt_session: None,
+            polling_options: PollingOptions::default(),
+            deploy_options: DeployOptions::default(),
+            package_options: PackageOptions::default(),
+            request_timeout: None,
+            connect_timeout: None,
+            proxies: Vec::new(),
+            root_certificates: Vec::new(),
+            user_agent: None,
+            retry_budget: DEFAULT_RETRY_DURATION,
+            retry_min_interval: DEFAULT_RETRY_MIN_INTERVAL,
+            retry_max_interval: DEFAULT_RETRY_MAX_INTERVAL,
+            middleware: Vec::new(),
+        }
+    }
+}
+
+impl SindriClientBuilder {
+    /// Sets the authentication options, which otherwise fall back to environment variables.
+    pub fn auth_options(mut self, auth_options: AuthOptions) -> Self {
+        self.auth_options = auth_options;
+        self
+    }
+
+    /// Authenticates every request with a [`JwtSession`] rather than an API key.
     ///
-    /// * `auth_options` - Optional authentication configuration. If not provided, will attempt to read from environment variables
-    /// * `polling_options` - Optional polling configuration for long-running operations
+    /// The session refreshes its access token as needed, so the client can be used by
+    /// long-running tools. Any API key from the auth options or environment is ignored.
+    pub fn jwt_session(mut self, session: JwtSession) -> Self {
+        self.jwt_session = Some(session);
+        self
+    }
+
+    /// Sets the polling options used while waiting on circuits and proofs.
+    pub fn polling_options(mut self, polling_options: PollingOptions) -> Self {
+        self.polling_options = polling_options;
+        self
+    }
+
+    /// Sets the options used when deploying projects.
+    pub fn deploy_options(mut self, deploy_options: DeployOptions) -> Self {
+        self.deploy_options = deploy_options;
+        self
+    }
+
+    /// Sets the options used when packaging project directories.
+    pub fn package_options(mut self, package_options: PackageOptions) -> Self {
+        self.package_options = package_options;
+        self
+    }
+
+    /// Sets a timeout for each HTTP request, from connecting until the response body is read.
     ///
-    /// # Environment Variables
+    /// There is no request timeout by default. Note that large uploads may take a while.
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets a timeout for establishing each HTTP connection.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Adds a proxy for HTTP requests.
     ///
-    /// * `SINDRI_API_KEY` - API key for authentication (if auth_options not provided)
-    /// * `SINDRI_BASE_URL` - Base URL for API requests (if auth_options not provided)
+    /// Proxies configured through the usual environment variables (`HTTPS_PROXY`, etc.)
+    /// are respected without calling this method.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Adds a trusted root certificate, e.g. for a TLS-intercepting corporate proxy.
+    pub fn add_root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with each request.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Sets the maximum total duration spent retrying a request after transient errors
+    /// (default: 60 seconds). A zero duration disables retries.
+    pub fn retry_budget(mut self, budget: Duration) -> Self {
+        self.retry_budget = budget;
+        self
+    }
+
+    /// Sets the minimum and maximum wait between retries of a request (default: 1 to 8 seconds).
+    pub fn retry_bounds(mut self, min_interval: Duration, max_interval: Duration) -> Self {
+        self.retry_min_interval = min_interval;
+        self.retry_max_interval = max_interval;
+        self
+    }
+
+    /// Appends a middleware layer which runs after the built-in middleware.
     ///
     /// # Examples
     ///
     /// ```
     /// use sindri::client::SindriClient;
-    /// let client = SindriClient::new(None, None); // inferring your API key from `SINDRI_API_KEY`
+    /// use reqwest_middleware::{Middleware, Next};
+    ///
+    /// struct TraceHeader;
+    ///
+    /// #[async_trait::async_trait]
+    /// impl Middleware for TraceHeader {
+    ///     async fn handle(
+    ///         &self,
+    ///         mut req: reqwest::Request,
+    ///         extensions: &mut http::Extensions,
+    ///         next: Next<'_>,
+    ///     ) -> reqwest_middleware::Result<reqwest::Response> {
+    ///         req.headers_mut().insert("x-trace-id", "abc123".parse().unwrap());
+    ///         next.run(req, extensions).await
+    ///     }
+    /// }
+    ///
+    /// let client = SindriClient::builder()
+    ///     .with_middleware(TraceHeader)
+    ///     .build()
+    ///     .unwrap();
     /// ```
-    pub fn new(auth_options: Option<AuthOptions>, polling_options: Option<PollingOptions>) -> Self {
+    pub fn with_middleware<M: Middleware>(mut self, middleware: M) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Builds the [`SindriClient`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the underlying HTTP client cannot be built,
+    /// e.g. because of an invalid root certificate.
+    pub fn build(self) -> Result<SindriClient, Error> {
         let mut headers = HeaderMap::new();
         headers.insert(
             "Sindri-Client",
@@ -153,18 +607,39 @@ impl SindriClient {
             .expect("Could not insert default rust client header"),
         );
 
-        #[allow(unused_mut)] // needed for VCR mutation
-        let mut client_builder = reqwest_middleware::ClientBuilder::new(
-            reqwest::Client::builder()
-                .default_headers(headers)
-                .zstd(true)
-                .build()
-                .expect("Could not build client"),
-        )
-        .with(HeaderDeduplicatorMiddleware)
-        .with(LoggingMiddleware)
-        .with(retry_client(None))
-        .with(ZstdRequestCompressionMiddleware);
+        let mut reqwest_builder = reqwest::Client::builder()
+            .default_headers(headers)
+            .zstd(true);
+        if let Some(timeout) = self.request_timeout {
+            reqwest_builder = reqwest_builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            reqwest_builder = reqwest_builder.connect_timeout(timeout);
+        }
+        for proxy in self.proxies {
+            reqwest_builder = reqwest_builder.proxy(proxy);
+        }
+        for certificate in self.root_certificates {
+            reqwest_builder = reqwest_builder.add_root_certificate(certificate);
+        }
+
+        let mut client_builder = reqwest_middleware::ClientBuilder::new(reqwest_builder.build()?)
+            .with(HeaderDeduplicatorMiddleware)
+            .with(LoggingMiddleware)
+            .with(RetryBufferedMiddleware(retry_client_with_bounds(
+                self.retry_budget,
+                self.retry_min_interval,
+                self.retry_max_interval,
+            )));
+        // The session must see the request before compression turns large bodies into
+        // streams, or an unauthorized request could not be cloned for its retry
+        if let Some(session) = self.jwt_session.clone() {
+            client_builder = client_builder.with(JwtSessionMiddleware(session));
+        }
+        client_builder = client_builder.with(ZstdRequestCompressionMiddleware);
+        for middleware in self.middleware {
+            client_builder = client_builder.with_arc(middleware);
+        }
 
         #[cfg(any(feature = "record", feature = "replay"))]
         {
@@ -186,26 +661,97 @@ impl SindriClient {
         let client = client_builder.build();
 
         // First try to read from auth_options, then from environment variables, then use default values
-        let auth = auth_options.unwrap_or_default();
+        let auth = self.auth_options;
         let base_url = auth
             .base_url
             .or_else(|| std::env::var("SINDRI_BASE_URL").ok())
             .unwrap_or_else(|| "https://sindri.app".to_string());
-        let api_key = auth
-            .api_key
-            .or_else(|| std::env::var("SINDRI_API_KEY").ok());
+        let api_key = match self.jwt_session {
+            Some(_) => None,
+            None => auth
+                .api_key
+                .or_else(|| std::env::var("SINDRI_API_KEY").ok()),
+        };
 
-        let config = Configuration {
+        let mut config = Configuration {
             base_path: base_url,
             bearer_access_token: api_key,
             client,
             ..Default::default()
         };
+        // The generated client sets the user agent on each request from the configuration
+        if let Some(user_agent) = self.user_agent {
+            config.user_agent = Some(user_agent);
+        }
 
-        Self {
+        Ok(SindriClient {
             config,
-            polling_options: polling_options.unwrap_or_default(),
-        }
+            polling_options: self.polling_options,
+            deploy_options: self.deploy_options,
+            package_options: self.package_options,
+            upload_retry_policy: retry_policy_with_bounds(
+                self.retry_budget,
+                self.retry_min_interval,
+                self.retry_max_interval,
+            ),
+            upload_progress: None,
+        })
+    }
+
+    /// Builds a [`blocking::SindriClient`](crate::blocking::SindriClient) for synchronous code.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the underlying HTTP client cannot be built
+    /// and [`Error::Io`] if the runtime of the blocking client cannot be created.
+    pub fn build_blocking(self) -> Result<crate::blocking::SindriClient, Error> {
+        crate::blocking::SindriClient::from_async(self.build()?)
+    }
+}
+
+impl SindriClient {
+    /// Creates a new Sindri API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth_options` - Optional authentication configuration. If not provided, will attempt to read from environment variables
+    /// * `polling_options` - Optional polling configuration for long-running operations
+    ///
+    /// # Environment Variables
+    ///
+    /// * `SINDRI_API_KEY` - API key for authentication (if auth_options not provided)
+    /// * `SINDRI_BASE_URL` - Base URL for API requests (if auth_options not provided)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sindri::client::SindriClient;
+    /// let client = SindriClient::new(None, None); // inferring your API key from `SINDRI_API_KEY`
+    /// ```
+    pub fn new(auth_options: Option<AuthOptions>, polling_options: Option<PollingOptions>) -> Self {
+        Self::builder()
+            .auth_options(auth_options.unwrap_or_default())
+            .polling_options(polling_options.unwrap_or_default())
+            .build()
+            .expect("Could not build client")
+    }
+
+    /// Returns a [`SindriClientBuilder`] to configure the HTTP stack of a new client.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sindri::client::SindriClient;
+    /// use std::time::Duration;
+    ///
+    /// let client = SindriClient::builder()
+    ///     .connect_timeout(Duration::from_secs(5))
+    ///     .user_agent("my-service/1.0")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn builder() -> SindriClientBuilder {
+        SindriClientBuilder::default()
     }
 
     /// Returns the configured API key
@@ -266,6 +812,34 @@ impl SindriClient {
         self
     }
 
+    /// Sets the polling strategy for this client.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sindri::client::{PollingStrategy, SindriClient};
+    /// use std::time::Duration;
+    ///
+    /// let client = SindriClient::default()
+    ///     .with_polling_strategy(PollingStrategy::Exponential {
+    ///         multiplier: 1.5,
+    ///         max_interval: Duration::from_secs(30),
+    ///     })
+    ///     .with_polling_jitter(0.2);
+    /// ```
+    pub fn with_polling_strategy(mut self, strategy: PollingStrategy) -> Self {
+        self.polling_options.strategy = strategy;
+        self
+    }
+
+    /// Sets the polling jitter for this client, as a fraction between `0.0` and `1.0`.
+    ///
+    /// See [`PollingOptions`] for details.
+    pub fn with_polling_jitter(mut self, jitter: f64) -> Self {
+        self.polling_options.jitter = jitter;
+        self
+    }
+
     /// Sets the polling timeout for this client.
     ///
     /// # Examples
@@ -297,11 +871,110 @@ impl SindriClient {
         self
     }
 
+    /// Sets a cancellation token which stops this client from waiting on jobs.
+    ///
+    /// When the token is cancelled, any in-progress wait in methods like
+    /// [`create_circuit`](Self::create_circuit) or [`prove_circuit`](Self::prove_circuit)
+    /// returns [`Error::Cancelled`] with the ID of the job so that it can be resumed later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sindri::{client::SindriClient, CancellationToken};
+    ///
+    /// let token = CancellationToken::new();
+    /// let client = SindriClient::default()
+    ///     .with_cancellation_token(token.clone());
+    ///
+    /// // Later, e.g. during shutdown
+    /// token.cancel();
+    /// ```
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.polling_options.cancellation_token = Some(token);
+        self
+    }
+
+    /// Sets whether new circuits record the digest of their project in `meta`
+    /// (see [`DeployOptions::attach_project_digest`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sindri::client::SindriClient;
+    ///
+    /// let client = SindriClient::default().with_project_digest_meta(true);
+    /// ```
+    pub fn with_project_digest_meta(mut self, attach: bool) -> Self {
+        self.deploy_options.attach_project_digest = attach;
+        self
+    }
+
+    /// Sets whether deploys reuse an existing circuit built from an identical project
+    /// (see [`DedupeMode`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sindri::client::{DedupeMode, SindriClient};
+    ///
+    /// let client = SindriClient::default().with_dedupe(DedupeMode::Reuse);
+    /// ```
+    pub fn with_dedupe(mut self, dedupe: DedupeMode) -> Self {
+        self.deploy_options.dedupe = dedupe;
+        self
+    }
+
+    /// Sets the options used when packaging project directories (see [`PackageOptions`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sindri::client::{PackageOptions, SindriClient};
+    ///
+    /// let client = SindriClient::default().with_package_options(PackageOptions {
+    ///     include_hidden: true,
+    ///     ..Default::default()
+    /// });
+    /// ```
+    pub fn with_package_options(mut self, package_options: PackageOptions) -> Self {
+        self.package_options = package_options;
+        self
+    }
+
+    /// Sets a callback which reports the progress of project uploads.
+    ///
+    /// The callback is called from the upload task each time a chunk of the project archive
+    /// is handed to the HTTP client, so it should return quickly. If a failed upload is retried,
+    /// progress starts over from zero with an incremented `attempt`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sindri::client::SindriClient;
+    ///
+    /// let client = SindriClient::default().with_upload_progress(|progress| {
+    ///     println!(
+    ///         "Uploaded {} of {} bytes",
+    ///         progress.bytes_sent, progress.total_bytes
+    ///     );
+    /// });
+    /// ```
+    pub fn with_upload_progress(
+        mut self,
+        callback: impl Fn(UploadProgress) + Send + Sync + 'static,
+    ) -> Self {
+        self.upload_progress = Some(UploadProgressCallback(Arc::new(callback)));
+        self
+    }
+
     /// Creates and deploys a new circuit from a local project.
     ///
     /// In order to generate proofs on Sindri, you must first deploy the zero-knowledge circuit or
     /// guest code with this method. Upon deployment, this method continuously polls the service to
-    /// track the compilation status until the process either completes successfully or fails.
+    /// track the compilation status until the process either completes successfully or fails
+    /// (see [`wait_for_circuit`](Self::wait_for_circuit)).
+    ///
+    /// Project directories are packaged according to the [`PackageOptions`] of the client.
     ///
     /// # Arguments
     ///
@@ -311,7 +984,17 @@ impl SindriClient {
     ///
     /// # Returns
     ///
-    /// Returns circuit information on successful compilation, or error if compilation fails or times out.
+    /// Returns circuit information on successful compilation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::JobFailed`] if compilation fails, [`Error::Timeout`] if compilation
+    /// does not finish within the configured polling timeout, and [`Error::Cancelled`] if the
+    /// configured cancellation token is cancelled while waiting.
+    ///
+    /// The cancellation token is part of the client's [`PollingOptions`]. To cancel a single
+    /// deploy, pass it its own options with
+    /// [`create_circuit_with_options`](Self::create_circuit_with_options).
     ///
     /// # Examples
     ///
@@ -336,64 +1019,70 @@ impl SindriClient {
         project: String,
         tags: Option<Vec<String>>,
         meta: Option<HashMap<String, String>>,
-    ) -> Result<CircuitInfoResponse, Box<dyn std::error::Error>> {
+    ) -> Result<CircuitInfoResponse, Error> {
+        self.create_circuit_with_options(project, tags, meta, self.polling_options.clone())
+            .await
+    }
+
+    /// Creates and deploys a new circuit like [`create_circuit`](Self::create_circuit), but
+    /// waits for compilation with the given polling options rather than those of the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - Path to a local project directory or an archive file (.zip, .tar, .tar.gz, .tgz)
+    /// * `tags` - Optional list of tags to identify the circuit
+    /// * `meta` - Optional metadata (key-value pairs) to associate with the circuit
+    /// * `polling_options` - How to poll for completion, including an optional cancellation token
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use sindri::{client::SindriClient, CancellationToken};
+    ///
+    /// let client = SindriClient::default();
+    /// let token = CancellationToken::new();
+    /// let mut polling_options = client.polling_options.clone();
+    /// polling_options.cancellation_token = Some(token.clone());
+    /// let deploy = client.create_circuit_with_options(
+    ///     "path/to/directory".to_string(),
+    ///     None,
+    ///     None,
+    ///     polling_options,
+    /// );
+    /// token.cancel();
+    /// assert!(deploy.await.is_err());
+    /// # });
+    /// ```
+    pub async fn create_circuit_with_options(
+        &self,
+        project: String,
+        tags: Option<Vec<String>>,
+        meta: Option<HashMap<String, String>>,
+        polling_options: PollingOptions,
+    ) -> Result<CircuitInfoResponse, Error> {
         let response = self.request_build(project, tags, meta).await?;
         let circuit_id = response.id();
         info!("Circuit created with ID: {}", circuit_id);
 
-        #[cfg(feature = "rich-terminal")]
-        let mut current_status = *response.status();
-        #[cfg(feature = "rich-terminal")]
-        let pb = ClockProgressBar::new(&format!("Job status: {}", current_status));
-
-        let start_time = std::time::Instant::now();
-        let mut status = circuit_status(&self.config, circuit_id).await?;
-        debug!("Initial circuit status: {:?}", status.status);
-
-        while !matches!(status.status, JobStatus::Ready | JobStatus::Failed) {
-            if let Some(timeout) = self.polling_options.timeout {
-                if start_time.elapsed() > timeout {
-                    warn!("Circuit compilation timed out after {:?}", timeout);
-                    return Err(
-                        "Circuit compilation did not complete within timeout duration".into(),
-                    );
-                }
-            }
-            std::thread::sleep(self.polling_options.interval);
-            status = circuit_status(&self.config, circuit_id).await?;
-            #[cfg(feature = "rich-terminal")]
-            if status.status != current_status {
-                pb.update_message(&format!("Job status: {}", status.status));
-                current_status = status.status;
-            }
-        }
-
-        match status.status {
-            JobStatus::Ready => info!(
-                "Circuit compilation completed successfully after {:?}",
-                start_time.elapsed()
-            ),
-            JobStatus::Failed => warn!(
-                "Circuit compilation failed after {:?}",
-                start_time.elapsed()
-            ),
-            _ => unreachable!(),
-        }
-
-        let circuit_info = circuit_detail(&self.config, circuit_id, None).await?;
-        Ok(circuit_info)
+        self.wait_for_circuit(circuit_id, polling_options).await
     }
 
     /// Blocking version of `create_circuit`.
     ///
     /// This method provides the same functionality as `create_circuit` but can be used
     /// in synchronous contexts. It internally creates a runtime to execute the async operation.
+    /// Prefer [`blocking::SindriClient`](crate::blocking::SindriClient), which reuses one runtime
+    /// across calls, when making several blocking calls.
+    ///
+    /// Returns [`Error::BlockingInAsync`] when called from within an async runtime.
     pub fn create_circuit_blocking(
         &self,
         project: String,
         tags: Option<Vec<String>>,
         meta: Option<HashMap<String, String>>,
-    ) -> Result<CircuitInfoResponse, Box<dyn std::error::Error>> {
+    ) -> Result<CircuitInfoResponse, Error> {
+        ensure_blocking_allowed()?;
         let runtime = tokio::runtime::Runtime::new()?;
         runtime.block_on(self.create_circuit(project, tags, meta))
     }
@@ -409,7 +1098,7 @@ impl SindriClient {
     /// Once deleted, the circuit will no longer be viewable on the Sindri dashboard
     /// and you will not be able to generate proofs from it. You should only delete a circuit
     /// if its existence may cause confusion or misuse.
-    pub async fn delete_circuit(&self, circuit_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+    pub async fn delete_circuit(&self, circuit_id: &str) -> Result<(), Error> {
         info!("Deleting circuit with ID: {}", circuit_id);
         circuit_delete(&self.config, circuit_id).await?;
         Ok(())
@@ -452,7 +1141,7 @@ impl SindriClient {
         &self,
         circuit_id: &str,
         download_path: String,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+    ) -> Result<(), Error> {
         info!("Cloning circuit with ID: {}", circuit_id);
         debug!("Download path: {}", download_path);
 
@@ -469,11 +1158,16 @@ impl SindriClient {
     ///
     /// This method provides the same functionality as `clone_circuit` but can be used
     /// in synchronous contexts. It internally creates a runtime to execute the async operation.
+    /// Prefer [`blocking::SindriClient`](crate::blocking::SindriClient), which reuses one runtime
+    /// across calls, when making several blocking calls.
+    ///
+    /// Returns [`Error::BlockingInAsync`] when called from within an async runtime.
     pub fn clone_circuit_blocking(
         &self,
         circuit_id: &str,
         download_path: String,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+    ) -> Result<(), Error> {
+        ensure_blocking_allowed()?;
         let runtime = tokio::runtime::Runtime::new()?;
         runtime.block_on(self.clone_circuit(circuit_id, download_path))
     }
@@ -507,7 +1201,7 @@ impl SindriClient {
         &self,
         circuit_id: &str,
         include_verification_key: Option<bool>,
-    ) -> Result<CircuitInfoResponse, Error<CircuitDetailError>> {
+    ) -> Result<CircuitInfoResponse, Error> {
         info!("Getting circuit with ID: {}", circuit_id);
         let circuit_info =
             circuit_detail(&self.config, circuit_id, include_verification_key).await?;
@@ -517,25 +1211,34 @@ impl SindriClient {
     /// Creates and generates a proof for a circuit.
     ///
     /// This method initiates proof generation and automatically polls the Sindri API until the proof
-    /// is either successfully generated or fails. The polling interval and timeout can be configured
-    /// through the client's `polling_options`.
+    /// is either successfully generated or fails (see [`wait_for_proof`](Self::wait_for_proof)).
+    /// The polling interval and timeout can be configured through the client's `polling_options`.
     ///
     /// # Arguments
     ///
     /// * `circuit_id` - ID of the circuit to prove
     /// * `proof_input` - Input values for the proof. Can be provided as a JSON object, &str, or String.
-    ///                   The format (JSON, TOML, base64, etc.) should match your circuit's expected input structure.
+    ///   The format (JSON, TOML, base64, etc.) should match your circuit's expected input structure.
     /// * `meta` - Optional metadata key-value pairs
-    /// * `verify` - Whether to verify the proof (server-side) after generation. A proof status
-    ///              of `Failed` would be returned if the proof is not valid.
+    /// * `verify` - Whether to verify the proof (server-side) after generation. The proof job
+    ///   fails if the proof is not valid.
     /// * `prover_implementation` - Optional specific prover implementation to use.
-    ///                            This field is generally for internal development only.
-    ///                            Sindri automatically selects the most performant implementation
-    ///                            based on your project's deployment details.
+    ///   This field is generally for internal development only.
+    ///   Sindri automatically selects the most performant implementation
+    ///   based on your project's deployment details.
     ///
     /// # Returns
     ///
-    /// Returns proof information on successful generation, or error if generation fails or times out.
+    /// Returns proof information on successful generation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::JobFailed`] if proof generation (or server-side verification) fails,
+    /// [`Error::Timeout`] if the proof does not finish within the configured polling timeout,
+    /// and [`Error::Cancelled`] if the configured cancellation token is cancelled while waiting.
+    ///
+    /// To cancel a single proof without affecting other calls, pass it its own options with
+    /// [`prove_circuit_with_options`](Self::prove_circuit_with_options).
     ///
     /// # Examples
     ///
@@ -556,45 +1259,72 @@ impl SindriClient {
         meta: Option<HashMap<String, String>>,
         verify: Option<bool>,
         prover_implementation: Option<String>,
-    ) -> Result<ProofInfoResponse, Box<dyn std::error::Error>> {
+    ) -> Result<ProofInfoResponse, Error> {
+        self.prove_circuit_with_options(
+            circuit_id,
+            proof_input,
+            meta,
+            verify,
+            prover_implementation,
+            self.polling_options.clone(),
+        )
+        .await
+    }
+
+    /// Creates and generates a proof like [`prove_circuit`](Self::prove_circuit), but waits
+    /// for the proof with the given polling options rather than those of the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `circuit_id` - ID of the circuit to prove
+    /// * `proof_input` - Input values for the proof
+    /// * `meta` - Optional metadata key-value pairs
+    /// * `verify` - Whether to verify the proof (server-side) after generation
+    /// * `prover_implementation` - Optional specific prover implementation to use
+    /// * `polling_options` - How to poll for completion, including an optional cancellation token
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use std::time::Duration;
+    /// use sindri::client::SindriClient;
+    ///
+    /// let client = SindriClient::default();
+    /// let mut polling_options = client.polling_options.clone();
+    /// polling_options.timeout = Some(Duration::from_secs(60));
+    /// let proof = client
+    ///     .prove_circuit_with_options("team_name/project_name:tag", "x=10,y=20", None, None, None, polling_options)
+    ///     .await
+    ///     .unwrap();
+    /// # });
+    /// ```
+    pub async fn prove_circuit_with_options(
+        &self,
+        circuit_id: &str,
+        proof_input: impl Into<ProofInput>,
+        meta: Option<HashMap<String, String>>,
+        verify: Option<bool>,
+        prover_implementation: Option<String>,
+        polling_options: PollingOptions,
+    ) -> Result<ProofInfoResponse, Error> {
         let proof_info = self
             .request_proof(circuit_id, proof_input, meta, verify, prover_implementation)
             .await?;
         let proof_id = proof_info.proof_id;
         info!("Proof generation started with ID: {}", proof_id);
 
-        let mut status = proof_status(&self.config, &proof_id).await?;
-        debug!("Initial proof status: {:?}", status.status);
-
-        let start_time = std::time::Instant::now();
-        while !matches!(status.status, JobStatus::Ready | JobStatus::Failed) {
-            if let Some(timeout) = self.polling_options.timeout {
-                if start_time.elapsed() > timeout {
-                    warn!("Proof generation timed out after {:?}", timeout);
-                    return Err("Proof generation did not complete within timeout duration".into());
-                }
-            }
-            std::thread::sleep(self.polling_options.interval);
-            status = proof_status(&self.config, &proof_id).await?;
-        }
-
-        match status.status {
-            JobStatus::Ready => info!(
-                "Proof generation completed successfully after {:?}",
-                start_time.elapsed()
-            ),
-            JobStatus::Failed => warn!("Proof generation failed after {:?}", start_time.elapsed()),
-            _ => unreachable!(),
-        }
-
-        let proof_info = proof_detail(&self.config, &proof_id, None, None, None, None).await?;
-        Ok(proof_info)
+        self.wait_for_proof(&proof_id, polling_options).await
     }
 
     /// Blocking version of `prove_circuit`.
     ///
     /// This method provides the same functionality as `prove_circuit` but can be used
     /// in synchronous contexts. It internally creates a runtime to execute the async operation.
+    /// Prefer [`blocking::SindriClient`](crate::blocking::SindriClient), which reuses one runtime
+    /// across calls, when making several blocking calls.
+    ///
+    /// Returns [`Error::BlockingInAsync`] when called from within an async runtime.
     pub fn prove_circuit_blocking(
         &self,
         circuit_id: &str,
@@ -602,7 +1332,8 @@ impl SindriClient {
         meta: Option<HashMap<String, String>>,
         verify: Option<bool>,
         prover_implementation: Option<String>,
-    ) -> Result<ProofInfoResponse, Box<dyn std::error::Error>> {
+    ) -> Result<ProofInfoResponse, Error> {
+        ensure_blocking_allowed()?;
         let runtime = tokio::runtime::Runtime::new()?;
         runtime.block_on(self.prove_circuit(
             circuit_id,
@@ -624,7 +1355,7 @@ impl SindriClient {
     /// Once deleted, the proof will no longer be viewable on the Sindri dashboard.
     /// You should only delete a proof if its existence may cause confusion and retrieval
     /// of the wrong proof details.
-    pub async fn delete_proof(&self, proof_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+    pub async fn delete_proof(&self, proof_id: &str) -> Result<(), Error> {
         info!("Deleting proof with ID: {}", proof_id);
         proof_delete(&self.config, proof_id).await?;
         Ok(())
@@ -670,7 +1401,7 @@ impl SindriClient {
         include_proof: Option<bool>,
         include_public: Option<bool>,
         include_verification_key: Option<bool>,
-    ) -> Result<ProofInfoResponse, Error<ProofDetailError>> {
+    ) -> Result<ProofInfoResponse, Error> {
         let proof_info = proof_detail(
             &self.config,
             proof_id,
@@ -687,13 +1418,51 @@ impl SindriClient {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::BoojumCircuitInfoResponse;
+    use crate::{BoojumCircuitInfoResponse, StatusCode};
+    use sindri_openapi::models::JobStatus;
     use tracing_test::traced_test;
     use wiremock::{
         matchers::{header_exists, method, path},
         Mock, MockServer, ResponseTemplate,
     };
 
+    fn assert_send_sync<T: Send + Sync + 'static>() {}
+
+    #[test]
+    fn test_client_is_send_sync() {
+        assert_send_sync::<SindriClient>();
+        assert_send_sync::<PollingOptions>();
+    }
+
+    #[tokio::test]
+    async fn test_cloned_client_shares_config() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header_exists("sindri-client"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let client = SindriClient::default()
+            .with_api_key("test_key")
+            .with_base_url(mock_server.uri());
+        let handle = {
+            let client = client.clone();
+            tokio::spawn(async move {
+                let request = client.config.client.get(client.base_url()).build().unwrap();
+                client
+                    .config
+                    .client
+                    .execute(request)
+                    .await
+                    .unwrap()
+                    .status()
+            })
+        };
+        assert_eq!(handle.await.unwrap(), 200);
+        assert_eq!(client.api_key(), Some("test_key"));
+    }
+
     #[test]
     fn test_new_client_with_options() {
         let auth_options = AuthOptions {
@@ -802,6 +1571,7 @@ mod tests {
         let polling_options = PollingOptions {
             interval: Duration::from_secs(5),
             timeout: Some(Duration::from_secs(300)), // 5 minutes
+            ..Default::default()
         };
         let client = SindriClient::new(None, Some(polling_options));
 
@@ -812,6 +1582,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_polling_strategy_intervals() {
+        let fixed = PollingOptions::default();
+        assert_eq!(fixed.next_interval(10, Some(600.0)), Duration::from_secs(1));
+
+        let exponential = PollingOptions {
+            strategy: PollingStrategy::Exponential {
+                multiplier: 2.0,
+                max_interval: Duration::from_secs(10),
+            },
+            ..Default::default()
+        };
+        let intervals: Vec<_> = (1..=6)
+            .map(|attempt| exponential.next_interval(attempt, None).as_secs())
+            .collect();
+        assert_eq!(intervals, vec![1, 2, 4, 8, 10, 10]);
+        assert_eq!(
+            exponential.next_interval(u32::MAX, None),
+            Duration::from_secs(10)
+        );
+
+        let adaptive = PollingOptions {
+            interval: Duration::from_secs(2),
+            strategy: PollingStrategy::Adaptive {
+                fraction: 0.1,
+                max_interval: Duration::from_secs(60),
+            },
+            ..Default::default()
+        };
+        assert_eq!(adaptive.next_interval(1, None), Duration::from_secs(2));
+        assert_eq!(adaptive.next_interval(1, Some(5.0)), Duration::from_secs(2));
+        assert_eq!(
+            adaptive.next_interval(5, Some(300.0)),
+            Duration::from_secs(30)
+        );
+        assert_eq!(
+            adaptive.next_interval(9, Some(3600.0)),
+            Duration::from_secs(60)
+        );
+    }
+
+    #[test]
+    fn test_polling_jitter_bounds() {
+        let client = SindriClient::default()
+            .with_polling_interval(Duration::from_secs(10))
+            .with_polling_jitter(0.5);
+        for attempt in 1..100 {
+            let interval = client.polling_options.next_interval(attempt, None);
+            assert!(interval >= Duration::from_secs(5));
+            assert!(interval <= Duration::from_secs(15));
+        }
+    }
+
     #[test]
     fn test_post_client_init_polling_tweaks() {
         let mut client = SindriClient::new(None, None);
@@ -849,6 +1672,64 @@ mod tests {
         assert_eq!(response.status(), 200);
     }
 
+    struct CountingMiddleware(Arc<std::sync::atomic::AtomicUsize>);
+
+    #[async_trait::async_trait]
+    impl Middleware for CountingMiddleware {
+        async fn handle(
+            &self,
+            req: reqwest::Request,
+            extensions: &mut http::Extensions,
+            next: reqwest_middleware::Next<'_>,
+        ) -> reqwest_middleware::Result<reqwest::Response> {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            next.run(req, extensions).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_builder_http_stack() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/proof/test_proof_123/detail"))
+            .and(wiremock::matchers::header("user-agent", "my-service/1.0"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&mock_server)
+            .await;
+
+        let counter = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let client = SindriClient::builder()
+            .auth_options(AuthOptions {
+                api_key: Some("test_key".to_string()),
+                base_url: Some(mock_server.uri()),
+            })
+            .user_agent("my-service/1.0")
+            .request_timeout(Duration::from_secs(5))
+            .connect_timeout(Duration::from_secs(5))
+            .retry_budget(Duration::ZERO)
+            .with_middleware(CountingMiddleware(counter.clone()))
+            .build()
+            .unwrap();
+        assert_eq!(client.api_key(), Some("test_key"));
+
+        // The user agent matched and the retry budget of zero results in a single attempt
+        let err = client
+            .get_proof("test_proof_123", None, None, None)
+            .await
+            .unwrap_err();
+        assert_eq!(err.status(), Some(StatusCode::INTERNAL_SERVER_ERROR));
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+        assert_eq!(counter.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_builder_with_proxy() {
+        let client = SindriClient::builder()
+            .proxy(reqwest::Proxy::all("http://localhost:3128").unwrap())
+            .build();
+        assert!(client.is_ok());
+    }
+
     #[tokio::test]
     async fn test_circuit_create_tag_validation() {
         let client = SindriClient::new(None, None);
@@ -969,4 +1850,74 @@ mod tests {
             }
         });
     }
+
+    #[tokio::test]
+    async fn test_cancel_proof_wait() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/circuit/test_circuit_123/prove"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(ProofInfoResponse {
+                proof_id: "test_proof_123".to_string(),
+                status: JobStatus::Queued,
+                ..Default::default()
+            }))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/proof/test_proof_123/status"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(ProofInfoResponse {
+                status: JobStatus::Queued,
+                ..Default::default()
+            }))
+            .mount(&mock_server)
+            .await;
+
+        // A long interval would stall this single-threaded test if the wait blocked the runtime
+        let token = CancellationToken::new();
+        let client = SindriClient::new(None, None)
+            .with_base_url(mock_server.uri())
+            .with_polling_interval(Duration::from_secs(60))
+            .with_cancellation_token(token.clone());
+
+        let canceller = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            token.cancel();
+        });
+        let start = std::time::Instant::now();
+        let result = client
+            .prove_circuit("test_circuit_123", "x=1", None, None, None)
+            .await;
+        canceller.await.unwrap();
+
+        assert!(start.elapsed() < Duration::from_secs(10));
+        let err = result.unwrap_err();
+        assert!(matches!(err, Error::Cancelled { .. }));
+        assert_eq!(err.job_id(), Some("test_proof_123"));
+
+        // A token passed for a single call cancels only that call
+        let client = SindriClient::new(None, None)
+            .with_base_url(mock_server.uri())
+            .with_polling_interval(Duration::from_secs(60));
+        let token = CancellationToken::new();
+        let mut polling_options = client.polling_options.clone();
+        polling_options.cancellation_token = Some(token.clone());
+        let canceller = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            token.cancel();
+        });
+        let result = client
+            .prove_circuit_with_options(
+                "test_circuit_123",
+                "x=1",
+                None,
+                None,
+                None,
+                polling_options,
+            )
+            .await;
+        canceller.await.unwrap();
+
+        assert!(matches!(result.unwrap_err(), Error::Cancelled { .. }));
+        assert!(client.polling_options.cancellation_token.is_none());
+    }
 }
@@ -3,8 +3,10 @@
 //! Important types of middleware implemented here:
 //!
 //! - `HeaderDeduplicatorMiddleware`: Removes duplicate headers from requests (bugfix for openapi client).
+//! - `JwtSessionMiddleware`: Authenticates requests with the access token of a `JwtSession`.
 //! - `LoggingMiddleware`: Logs requests and responses.
 //! - `Retry500`: Implements a retry policy for 500-series errors.
+//! - `RetryBufferedMiddleware`: Only retries requests whose bodies can be cloned.
 //! - `VCRMiddleware`: Records and replays requests for (internal) testing purposes.
 //! - `ZstdRequestCompressionMiddleware`: Compresses request bodies using zstd.
 
@@ -15,7 +17,10 @@ use crate::vendor::rvcr::{VCRMiddleware, VCRMode};
 use async_compression::tokio::write::ZstdEncoder;
 use async_trait::async_trait;
 use http::Extensions;
-use reqwest::{header::HeaderValue, header::CONTENT_ENCODING, Body, Request, Response, StatusCode};
+use reqwest::{
+    header::{HeaderValue, AUTHORIZATION, CONTENT_ENCODING},
+    Body, Request, Response, StatusCode,
+};
 use reqwest_middleware::{Middleware, Next};
 use reqwest_retry::{
     default_on_request_failure,
@@ -29,9 +34,24 @@ use tokio::io::AsyncWriteExt;
 use tokio_util::io::ReaderStream;
 use tracing::{debug, error};
 
+use crate::jwt::JwtSession;
+
 const ZSTD_BUFFER_SIZE: usize = 4096;
 const ZSTD_MIN_BODY_SIZE: usize = 512;
 
+// Default retry policy for transient errors
+pub(crate) const DEFAULT_RETRY_DURATION: Duration = Duration::from_secs(60);
+pub(crate) const DEFAULT_RETRY_MIN_INTERVAL: Duration = Duration::from_secs(1);
+pub(crate) const DEFAULT_RETRY_MAX_INTERVAL: Duration = Duration::from_secs(8);
+
+// 500, 502, 503, 504 indicate a server error that may soon be resolved.
+pub(crate) const TRANSIENT_STATUS_CODES: [StatusCode; 4] = [
+    StatusCode::INTERNAL_SERVER_ERROR,
+    StatusCode::BAD_GATEWAY,
+    StatusCode::SERVICE_UNAVAILABLE,
+    StatusCode::GATEWAY_TIMEOUT,
+];
+
 pub struct HeaderDeduplicatorMiddleware;
 
 /// Custom middleware to deduplicate headers
@@ -70,6 +90,53 @@ impl Middleware for HeaderDeduplicatorMiddleware {
     }
 }
 
+pub struct JwtSessionMiddleware(pub JwtSession);
+
+impl JwtSessionMiddleware {
+    async fn authorize(&self, req: &mut Request) -> Result<(), reqwest_middleware::Error> {
+        let token = self
+            .0
+            .access_token()
+            .await
+            .map_err(reqwest_middleware::Error::middleware)?;
+        let value = HeaderValue::from_str(&format!("Bearer {}", token))
+            .map_err(reqwest_middleware::Error::middleware)?;
+        req.headers_mut().insert(AUTHORIZATION, value);
+        Ok(())
+    }
+}
+
+/// Custom middleware to authenticate with a JWT session
+///
+/// The session's access token replaces any bearer token set by the openapi client.
+/// A request rejected as unauthorized is retried once after refreshing the token,
+/// provided that its body can be cloned, so it must run before request compression.
+#[async_trait::async_trait]
+impl Middleware for JwtSessionMiddleware {
+    async fn handle(
+        &self,
+        mut req: reqwest::Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> Result<reqwest::Response, reqwest_middleware::Error> {
+        self.authorize(&mut req).await?;
+        let retry = req.try_clone();
+        let response = next.clone().run(req, extensions).await?;
+        match retry {
+            Some(mut retry) if response.status() == StatusCode::UNAUTHORIZED => {
+                debug!("Request unauthorized, retrying with a refreshed JWT");
+                self.0
+                    .refresh()
+                    .await
+                    .map_err(reqwest_middleware::Error::middleware)?;
+                self.authorize(&mut retry).await?;
+                next.run(retry, extensions).await
+            }
+            _ => Ok(response),
+        }
+    }
+}
+
 pub struct LoggingMiddleware;
 
 /// Simple logging of requests and responses
@@ -127,17 +194,9 @@ impl RetryableStrategy for Retry500 {
             StatusCode::from_u16(510).unwrap(),
         ];
 
-        // 500, 502, 503, 504 indicate a server error that may soon be resolved.
-        let transient_codes = [
-            StatusCode::from_u16(500).unwrap(),
-            StatusCode::from_u16(502).unwrap(),
-            StatusCode::from_u16(503).unwrap(),
-            StatusCode::from_u16(504).unwrap(),
-        ];
-
         match res {
             // retry if temporary API outage: 500, 502, 503, or 504
-            Ok(success) if transient_codes.contains(&success.status()) => {
+            Ok(success) if TRANSIENT_STATUS_CODES.contains(&success.status()) => {
                 debug!(
                     "Retrying request due to temporary API outage: {}",
                     success.status()
@@ -164,18 +223,53 @@ impl RetryableStrategy for Retry500 {
 }
 
 /// Returns a HTTP client which will retry requests with response errors meeting the retry500 "transient error" classification
-/// Default behavior is a retry at random times between 1s and 8s for a default maximum duration of 60s.
+/// Retries happen at random times between `min_interval` and `max_interval` for a maximum duration of `max_duration`.
 ///
-/// The retry policy is configurable with `max_duration` which defaults to 60s.
-pub fn retry_client(
-    max_duration: Option<Duration>,
+/// The `SindriClient` defaults are a retry between 1s and 8s for a maximum duration of 60s.
+pub fn retry_client_with_bounds(
+    max_duration: Duration,
+    min_interval: Duration,
+    max_interval: Duration,
 ) -> RetryTransientMiddleware<ExponentialBackoffTimed, Retry500> {
-    let retry_policy = ExponentialBackoff::builder()
-        .retry_bounds(Duration::from_secs(1), Duration::from_secs(8))
-        .build_with_total_retry_duration(max_duration.unwrap_or(Duration::from_secs(60)));
+    let retry_policy = retry_policy_with_bounds(max_duration, min_interval, max_interval);
     RetryTransientMiddleware::new_with_policy_and_strategy(retry_policy, Retry500)
 }
 
+/// Returns the backoff policy of [`retry_client_with_bounds`], for retrying operations
+/// which cannot pass through the retry middleware (e.g. streamed uploads).
+pub(crate) fn retry_policy_with_bounds(
+    max_duration: Duration,
+    min_interval: Duration,
+    max_interval: Duration,
+) -> ExponentialBackoffTimed {
+    ExponentialBackoff::builder()
+        .retry_bounds(min_interval, max_interval.max(min_interval))
+        .build_with_total_retry_duration(max_duration)
+}
+
+/// Wraps a retry middleware so that it only handles requests whose bodies can be cloned.
+///
+/// The retry middleware rejects requests with streaming bodies outright, since they cannot be
+/// replayed. Those requests are passed on without retries instead; callers which stream a body
+/// from a re-readable source (e.g. project uploads) retry by rebuilding the request.
+pub struct RetryBufferedMiddleware<M>(pub M);
+
+#[async_trait]
+impl<M: Middleware> Middleware for RetryBufferedMiddleware<M> {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        if req.body().is_some_and(|body| body.as_bytes().is_none()) {
+            debug!("Skipping retries for request with a streaming body");
+            return next.run(req, extensions).await;
+        }
+        self.0.handle(req, extensions, next).await
+    }
+}
+
 /// Returns record & replay middleware for testing purposes
 #[cfg(any(feature = "record", feature = "replay"))]
 pub fn vcr_middleware(bundle: std::path::PathBuf) -> VCRMiddleware {
@@ -360,7 +454,11 @@ mod tests {
                 .build()
                 .expect("Could not build client"),
         )
-        .with(retry_client(Some(Duration::from_secs(15))))
+        .with(retry_client_with_bounds(
+            Duration::from_secs(15),
+            DEFAULT_RETRY_MIN_INTERVAL,
+            DEFAULT_RETRY_MAX_INTERVAL,
+        ))
         .build();
 
         // Make the request
@@ -393,7 +491,11 @@ mod tests {
                 .build()
                 .expect("Could not build client"),
         )
-        .with(retry_client(None))
+        .with(retry_client_with_bounds(
+            DEFAULT_RETRY_DURATION,
+            DEFAULT_RETRY_MIN_INTERVAL,
+            DEFAULT_RETRY_MAX_INTERVAL,
+        ))
         .build();
 
         let request = client.get(mock_server.uri()).build().unwrap();
@@ -0,0 +1,332 @@
+//! Error type returned by all fallible methods of the Sindri SDK.
+
+use std::{fmt, time::Duration};
+
+pub use reqwest::StatusCode;
+use reqwest_retry::{default_on_request_failure, Retryable};
+use sindri_openapi::apis::Error as OpenApiError;
+
+use crate::custom_middleware::TRANSIENT_STATUS_CODES;
+
+/// The error type for all [`SindriClient`](crate::client::SindriClient) operations.
+///
+/// Every variant carries enough structure to be matched on directly, so callers
+/// never need to inspect the rendered error message.
+///
+/// # Examples
+///
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// use sindri::{client::SindriClient, Error, StatusCode};
+///
+/// let client = SindriClient::default();
+/// match client.get_circuit("team_name/project_name:tag", None).await {
+///     Ok(circuit) => println!("{:?}", circuit),
+///     Err(Error::Api { status: StatusCode::NOT_FOUND, .. }) => println!("No such circuit"),
+///     Err(e) => eprintln!("{}", e),
+/// }
+/// # });
+/// ```
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// An argument supplied to the SDK was rejected before any request was sent,
+    /// e.g. an invalid tag or a project path that does not exist.
+    Validation(String),
+    /// A local project could not be prepared for upload,
+    /// e.g. a missing or invalid Sindri manifest or an oversized project.
+    Packaging(String),
+    /// A project's Sindri manifest does not match the manifest schema.
+    ///
+    /// Every violation of the schema is reported, so that they can all be fixed at once.
+    InvalidManifest { violations: Vec<ManifestViolation> },
+    /// A local filesystem operation failed.
+    Io(std::io::Error),
+    /// The HTTP request could not be completed (connection failure, exhausted retries, etc.).
+    Http(reqwest_middleware::Error),
+    /// The Sindri API responded with an error status code.
+    Api {
+        /// HTTP status code of the response
+        status: StatusCode,
+        /// Raw response body
+        content: String,
+        /// Response body parsed into the typed error model of the endpoint, if possible
+        entity: Option<serde_json::Value>,
+    },
+    /// A request or response body could not be (de)serialized.
+    Serde(serde_json::Error),
+    /// The Sindri API responded successfully, but the response lacks a value the SDK
+    /// relies on or holds one which could not be interpreted.
+    UnexpectedResponse(String),
+    /// Proof data or a verification key is missing or could not be decoded or verified.
+    ProofData {
+        message: String,
+        source: Option<Box<dyn std::error::Error + Send + Sync>>,
+    },
+    /// A circuit or proof did not finish processing within the polling timeout.
+    Timeout { id: String, timeout: Duration },
+    /// A circuit failed to compile or a proof failed to generate.
+    JobFailed { id: String, message: Option<String> },
+    /// Waiting on a circuit or proof was stopped by a cancellation token.
+    ///
+    /// The job itself is not cancelled on Sindri and may still complete.
+    Cancelled { id: String },
+    /// A batch was cancelled before this input was submitted, so no job was created for it.
+    NotSubmitted,
+    /// A circuit has no smart contract verifier, because its proving scheme does not
+    /// support on-chain verification.
+    NoSmartContractVerifier { id: String },
+    /// A blocking method was called from within an async runtime, where it would deadlock
+    /// or panic. The async client should be used there instead.
+    BlockingInAsync,
+}
+
+/// A single violation of the manifest schema, as reported by [`Error::InvalidManifest`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ManifestViolation {
+    /// JSON pointer to the offending field, e.g. `/curve`, or an empty string for the
+    /// manifest itself
+    pub path: String,
+    /// Description of the violation
+    pub message: String,
+}
+
+impl fmt::Display for ManifestViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.path.as_str() {
+            "" => write!(f, "{}", self.message),
+            path => write!(f, "{}: {}", path, self.message),
+        }
+    }
+}
+
+impl Error {
+    /// Returns the HTTP status code if this error originated from an API response.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Error::Api { status, .. } => Some(*status),
+            Error::Http(e) => e.status(),
+            _ => None,
+        }
+    }
+
+    /// Returns the ID of the circuit or proof this error refers to, if any.
+    ///
+    /// This is useful to resume waiting on a job after a timeout or cancellation.
+    pub fn job_id(&self) -> Option<&str> {
+        match self {
+            Error::Timeout { id, .. } | Error::JobFailed { id, .. } | Error::Cancelled { id } => {
+                Some(id)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns whether a failed request may succeed if it is sent again.
+    pub(crate) fn is_transient(&self) -> bool {
+        match self {
+            Error::Http(e) => default_on_request_failure(e) == Some(Retryable::Transient),
+            e => e
+                .status()
+                .is_some_and(|status| TRANSIENT_STATUS_CODES.contains(&status)),
+        }
+    }
+
+    pub(crate) fn proof_data(message: impl Into<String>) -> Self {
+        Error::ProofData {
+            message: message.into(),
+            source: None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Validation(message) | Error::Packaging(message) => write!(f, "{}", message),
+            Error::InvalidManifest { violations } => {
+                write!(f, "Invalid {}:", crate::utils::SINDRI_MANIFEST_FILENAME)?;
+                for violation in violations {
+                    write!(f, "\n  - {}", violation)?;
+                }
+                Ok(())
+            }
+            Error::Io(e) => write!(f, "IO error: {}", e),
+            Error::Http(e) => write!(f, "HTTP request failed: {}", e),
+            Error::Api {
+                status, content, ..
+            } => {
+                if content.is_empty() {
+                    write!(f, "API request failed with status code {}", status)
+                } else {
+                    write!(
+                        f,
+                        "API request failed with status code {}: {}",
+                        status, content
+                    )
+                }
+            }
+            Error::Serde(e) => write!(f, "Serialization error: {}", e),
+            Error::UnexpectedResponse(message) => write!(f, "Unexpected API response: {}", message),
+            Error::ProofData { message, .. } => write!(f, "{}", message),
+            Error::Timeout { id, timeout } => {
+                write!(f, "Job {} did not complete within {:?}", id, timeout)
+            }
+            Error::JobFailed { id, message } => match message {
+                Some(message) => write!(f, "Job {} failed: {}", id, message),
+                None => write!(f, "Job {} failed", id),
+            },
+            Error::Cancelled { id } => write!(f, "Stopped waiting for job {}", id),
+            Error::NotSubmitted => write!(f, "Input was not submitted before the batch was cancelled"),
+            Error::NoSmartContractVerifier { id } => {
+                write!(f, "Circuit {} has no smart contract verifier", id)
+            }
+            Error::BlockingInAsync => write!(
+                f,
+                "Blocking methods cannot be called from within an async runtime, use the async client instead"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Http(e) => Some(e),
+            Error::Serde(e) => Some(e),
+            Error::ProofData {
+                source: Some(e), ..
+            } => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Serde(e)
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        Error::Http(reqwest_middleware::Error::Reqwest(e))
+    }
+}
+
+impl From<reqwest_middleware::Error> for Error {
+    fn from(e: reqwest_middleware::Error) -> Self {
+        Error::Http(e)
+    }
+}
+
+/// Flattens the per-endpoint errors of the generated client into [`Error`].
+///
+/// The typed error entity of the endpoint is preserved as a JSON value.
+impl<T: serde::Serialize> From<OpenApiError<T>> for Error {
+    fn from(e: OpenApiError<T>) -> Self {
+        match e {
+            OpenApiError::Reqwest(e) => e.into(),
+            OpenApiError::ReqwestMiddleware(e) => e.into(),
+            OpenApiError::Serde(e) => e.into(),
+            OpenApiError::Io(e) => e.into(),
+            OpenApiError::ResponseError(response) => Error::Api {
+                status: response.status,
+                content: response.content,
+                entity: response
+                    .entity
+                    .and_then(|entity| serde_json::to_value(entity).ok()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sindri_openapi::{
+        apis::{circuits_api::CircuitDetailError, ResponseContent},
+        models::CircuitDoesNotExistResponse,
+    };
+    use std::error::Error as _;
+
+    fn assert_send_sync<T: Send + Sync + 'static>() {}
+
+    #[test]
+    fn test_error_is_send_sync() {
+        assert_send_sync::<Error>();
+    }
+
+    #[test]
+    fn test_is_transient() {
+        let api_error = |status: u16| Error::Api {
+            status: StatusCode::from_u16(status).unwrap(),
+            content: String::new(),
+            entity: None,
+        };
+        assert!(api_error(502).is_transient());
+        assert!(!api_error(404).is_transient());
+        assert!(!api_error(501).is_transient());
+        assert!(!Error::Validation("bad tag".to_string()).is_transient());
+    }
+
+    #[test]
+    fn test_response_error_conversion() {
+        let openapi_error = OpenApiError::ResponseError(ResponseContent {
+            status: StatusCode::NOT_FOUND,
+            content: r#"{"error":"CircuitDoesNotExist"}"#.to_string(),
+            entity: Some(CircuitDetailError::Status404(CircuitDoesNotExistResponse {
+                error: "CircuitDoesNotExist".to_string(),
+                ..Default::default()
+            })),
+        });
+
+        let error = Error::from(openapi_error);
+        assert_eq!(error.status(), Some(StatusCode::NOT_FOUND));
+        match error {
+            Error::Api { entity, .. } => {
+                assert_eq!(entity.unwrap()["error"], "CircuitDoesNotExist");
+            }
+            _ => panic!("Expected Api variant"),
+        }
+    }
+
+    #[test]
+    fn test_source_chain() {
+        let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "missing");
+        let error = Error::from(io_error);
+        assert!(error.source().is_some());
+        assert!(error.to_string().contains("missing"));
+
+        let error = Error::Validation("bad tag".to_string());
+        assert!(error.source().is_none());
+        assert_eq!(error.to_string(), "bad tag");
+    }
+
+    #[test]
+    fn test_invalid_manifest_display() {
+        let error = Error::InvalidManifest {
+            violations: vec![
+                ManifestViolation {
+                    path: String::new(),
+                    message: "\"name\" is a required property".to_string(),
+                },
+                ManifestViolation {
+                    path: "/curve".to_string(),
+                    message: "\"bn128\" is not one of [\"bn254\"]".to_string(),
+                },
+            ],
+        };
+        assert_eq!(
+            error.to_string(),
+            "Invalid sindri.json:\n  - \"name\" is a required property\n  - /curve: \"bn128\" is not one of [\"bn254\"]"
+        );
+    }
+}
@@ -0,0 +1,444 @@
+//! These methods generate many proofs for the same circuit concurrently,
+//! with a bound on the number of proofs in flight at any time.
+
+use std::{collections::HashMap, time::Instant};
+
+use futures::future::join_all;
+use sindri_openapi::models::{JobStatus, ProofInfoResponse};
+use tracing::{debug, info, warn};
+
+use crate::{
+    client::{PollingOptions, SindriClient},
+    job_wait::{poll_interval, JobKind},
+    types::ProofInput,
+    Error,
+};
+
+/// Configuration options for [`SindriClient::prove_batch`].
+///
+/// # Fields
+///
+/// * `max_concurrency` - Maximum number of proofs submitted to Sindri and not yet finished
+///   at any time (default: 10). Further inputs are submitted as earlier proofs finish.
+/// * `meta` - Optional metadata key-value pairs attached to every proof
+/// * `verify` - Whether to verify each proof (server-side) after generation
+/// * `prover_implementation` - Optional specific prover implementation to use
+/// * `polling_options` - Polling configuration for the batch, which defaults to the client's.
+///   The timeout applies to each proof individually, counted from its submission. Batch status
+///   checks do not report queue and compute times, so
+///   [`PollingStrategy::Adaptive`](crate::client::PollingStrategy::Adaptive) uses the
+///   time since the most recent pending proof was submitted as the age of the jobs.
+///
+/// # Examples
+///
+/// ```
+/// use sindri::client::BatchOptions;
+///
+/// let options = BatchOptions {
+///     max_concurrency: 50,
+///     verify: Some(true),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone)]
+pub struct BatchOptions {
+    pub max_concurrency: usize,
+    pub meta: Option<HashMap<String, String>>,
+    pub verify: Option<bool>,
+    pub prover_implementation: Option<String>,
+    pub polling_options: Option<PollingOptions>,
+}
+impl Default for BatchOptions {
+    fn default() -> Self {
+        Self {
+            max_concurrency: 10,
+            meta: None,
+            verify: None,
+            prover_implementation: None,
+            polling_options: None,
+        }
+    }
+}
+
+/// Consecutive transient failures of status checks after which a proof is reported as failed.
+const MAX_STATUS_FAILURES: u32 = 3;
+
+/// A proof which has been submitted but has not finished processing.
+struct PendingProof {
+    index: usize,
+    proof_id: String,
+    submitted_at: Instant,
+    status_failures: u32,
+}
+
+impl SindriClient {
+    /// Generates proofs for many inputs to the same circuit.
+    ///
+    /// Inputs are submitted with [`request_proof`](Self::request_proof), keeping at most
+    /// `max_concurrency` proofs in flight. All pending proofs are polled together, once per
+    /// polling interval, rather than each proof polling on its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `circuit_id` - ID of the circuit to prove
+    /// * `inputs` - Input values for each proof
+    /// * `options` - Options shared by all proofs of the batch
+    ///
+    /// # Returns
+    ///
+    /// Returns one result per input, in the same order as the inputs. A failure for one input
+    /// (e.g. a rejected submission or [`Error::JobFailed`]) does not affect the others. Status
+    /// checks which fail with a transient error are retried at the next polling interval, up to
+    /// `MAX_STATUS_FAILURES` times in a row for each proof.
+    /// If the cancellation token is cancelled, pending proofs report [`Error::Cancelled`] with
+    /// their ID, and inputs which were never submitted report [`Error::NotSubmitted`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use sindri::client::{BatchOptions, SindriClient};
+    ///
+    /// let client = SindriClient::default();
+    /// let inputs: Vec<String> = (0..1000).map(|x| format!("x={},y=20", x)).collect();
+    /// let results = client
+    ///     .prove_batch("team_name/project_name:tag", inputs, BatchOptions::default())
+    ///     .await;
+    /// for (index, result) in results.iter().enumerate() {
+    ///     match result {
+    ///         Ok(proof) => println!("Input {}: proof {}", index, proof.proof_id),
+    ///         Err(e) => println!("Input {}: {}", index, e),
+    ///     }
+    /// }
+    /// # });
+    /// ```
+    pub async fn prove_batch<I: Into<ProofInput>>(
+        &self,
+        circuit_id: &str,
+        inputs: impl IntoIterator<Item = I>,
+        options: BatchOptions,
+    ) -> Vec<Result<ProofInfoResponse, Error>> {
+        let polling_options = options
+            .polling_options
+            .clone()
+            .unwrap_or_else(|| self.polling_options.clone());
+        let max_concurrency = options.max_concurrency.max(1);
+
+        let mut queue = inputs
+            .into_iter()
+            .map(Into::into)
+            .enumerate()
+            .collect::<Vec<_>>()
+            .into_iter();
+        let mut results: Vec<Option<Result<ProofInfoResponse, Error>>> =
+            (0..queue.len()).map(|_| None).collect();
+        info!(
+            "Submitting batch of {} proofs for circuit {}",
+            results.len(),
+            circuit_id
+        );
+
+        let mut pending: Vec<PendingProof> = Vec::new();
+        let mut attempt = 0;
+        loop {
+            // Fill any free slots with new submissions
+            let submissions = join_all(queue.by_ref().take(max_concurrency - pending.len()).map(
+                |(index, input)| {
+                    let options = &options;
+                    async move {
+                        let proof = self
+                            .request_proof(
+                                circuit_id,
+                                input,
+                                options.meta.clone(),
+                                options.verify,
+                                options.prover_implementation.clone(),
+                            )
+                            .await;
+                        (index, proof)
+                    }
+                },
+            ))
+            .await;
+            for (index, proof) in submissions {
+                match proof {
+                    Ok(proof) => pending.push(PendingProof {
+                        index,
+                        proof_id: proof.proof_id,
+                        submitted_at: Instant::now(),
+                        status_failures: 0,
+                    }),
+                    Err(e) => results[index] = Some(Err(e)),
+                }
+            }
+            if pending.is_empty() {
+                if queue.len() == 0 {
+                    break;
+                }
+                continue;
+            }
+
+            attempt += 1;
+            let job_age_sec = pending
+                .iter()
+                .map(|proof| proof.submitted_at.elapsed().as_secs_f64())
+                .reduce(f64::min);
+            let interval = polling_options.next_interval(attempt, job_age_sec);
+            if poll_interval(circuit_id, interval, &polling_options)
+                .await
+                .is_err()
+            {
+                for proof in pending.drain(..) {
+                    results[proof.index] = Some(Err(Error::Cancelled { id: proof.proof_id }));
+                }
+                for (index, _) in queue.by_ref() {
+                    results[index] = Some(Err(Error::NotSubmitted));
+                }
+                break;
+            }
+
+            // Poll every pending proof at once
+            let statuses = join_all(
+                pending
+                    .iter()
+                    .map(|proof| self.job_status(JobKind::Proof, &proof.proof_id)),
+            )
+            .await;
+            let mut finished = Vec::new();
+            let mut still_pending = Vec::new();
+            for (proof, status) in pending.drain(..).zip(statuses) {
+                match status {
+                    Ok(status @ (JobStatus::Ready | JobStatus::Failed)) => {
+                        finished.push((proof, status))
+                    }
+                    Err(e)
+                        if e.is_transient() && proof.status_failures + 1 < MAX_STATUS_FAILURES =>
+                    {
+                        warn!(
+                            "Status check of proof {} failed ({}), retrying",
+                            proof.proof_id, e
+                        );
+                        still_pending.push(PendingProof {
+                            status_failures: proof.status_failures + 1,
+                            ..proof
+                        });
+                    }
+                    Err(e) => results[proof.index] = Some(Err(e)),
+                    Ok(_) => match polling_options.timeout {
+                        Some(timeout) if proof.submitted_at.elapsed() > timeout => {
+                            warn!("Proof generation timed out after {:?}", timeout);
+                            results[proof.index] = Some(Err(Error::Timeout {
+                                id: proof.proof_id,
+                                timeout,
+                            }));
+                        }
+                        _ => still_pending.push(PendingProof {
+                            status_failures: 0,
+                            ..proof
+                        }),
+                    },
+                }
+            }
+            pending = still_pending;
+
+            let details = join_all(
+                finished
+                    .iter()
+                    .map(|(proof, status)| self.finished_proof(&proof.proof_id, *status)),
+            )
+            .await;
+            for ((proof, status), detail) in finished.into_iter().zip(details) {
+                debug!("Proof {} finished with status {:?}", proof.proof_id, status);
+                results[proof.index] = Some(detail);
+            }
+        }
+
+        let failures = results
+            .iter()
+            .filter(|result| !matches!(result, Some(Ok(_))))
+            .count();
+        info!(
+            "Batch completed with {} of {} proofs failed",
+            failures,
+            results.len()
+        );
+        results
+            .into_iter()
+            .map(|result| result.expect("Every input is resolved before the batch completes"))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use wiremock::{
+        matchers::{body_string_contains, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    async fn mount_proof(mock_server: &MockServer, input: &str, proof_id: &str, status: JobStatus) {
+        Mock::given(method("POST"))
+            .and(path("/api/v1/circuit/test_circuit_123/prove"))
+            .and(body_string_contains(input))
+            .respond_with(ResponseTemplate::new(201).set_body_json(ProofInfoResponse {
+                proof_id: proof_id.to_string(),
+                status: JobStatus::Queued,
+                ..Default::default()
+            }))
+            .mount(mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!("/api/v1/proof/{}/status", proof_id)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(ProofInfoResponse {
+                status,
+                ..Default::default()
+            }))
+            .mount(mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!("/api/v1/proof/{}/detail", proof_id)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(ProofInfoResponse {
+                proof_id: proof_id.to_string(),
+                status,
+                error: Some(Some("out of memory".to_string())),
+                ..Default::default()
+            }))
+            .mount(mock_server)
+            .await;
+    }
+
+    async fn mock_batch_server() -> MockServer {
+        let mock_server = MockServer::start().await;
+        mount_proof(&mock_server, "x=1", "proof_1", JobStatus::Ready).await;
+        mount_proof(&mock_server, "x=2", "proof_2", JobStatus::Failed).await;
+        mount_proof(&mock_server, "x=3", "proof_3", JobStatus::Ready).await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/circuit/test_circuit_123/prove"))
+            .and(body_string_contains("bad"))
+            .respond_with(ResponseTemplate::new(422))
+            .mount(&mock_server)
+            .await;
+        mock_server
+    }
+
+    fn batch_options(max_concurrency: usize) -> BatchOptions {
+        BatchOptions {
+            max_concurrency,
+            polling_options: Some(PollingOptions {
+                interval: Duration::from_millis(10),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn assert_batch_results(results: &[Result<ProofInfoResponse, Error>]) {
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_ref().unwrap().proof_id, "proof_1");
+        assert!(matches!(
+            &results[1],
+            Err(Error::JobFailed { id, .. }) if id == "proof_2"
+        ));
+        assert_eq!(
+            results[2].as_ref().unwrap_err().status(),
+            Some(crate::StatusCode::UNPROCESSABLE_ENTITY)
+        );
+        assert_eq!(results[3].as_ref().unwrap().proof_id, "proof_3");
+    }
+
+    #[tokio::test]
+    async fn test_prove_batch_preserves_order() {
+        let mock_server = mock_batch_server().await;
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+
+        let results = client
+            .prove_batch(
+                "test_circuit_123",
+                ["x=1", "x=2", "bad", "x=3"],
+                batch_options(10),
+            )
+            .await;
+        assert_batch_results(&results);
+    }
+
+    #[tokio::test]
+    async fn test_prove_batch_bounded_concurrency() {
+        let mock_server = mock_batch_server().await;
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+
+        let results = client
+            .prove_batch(
+                "test_circuit_123",
+                ["x=1", "x=2", "bad", "x=3"],
+                batch_options(1),
+            )
+            .await;
+        assert_batch_results(&results);
+
+        // With one proof in flight at a time, each proof is submitted only after the
+        // previous one finished, i.e. after its detail was fetched
+        let requests = mock_server.received_requests().await.unwrap();
+        let paths: Vec<_> = requests.iter().map(|r| r.url.path().to_string()).collect();
+        let position = |p: &str| paths.iter().position(|x| x == p).unwrap();
+        assert!(
+            position("/api/v1/proof/proof_1/detail") < position("/api/v1/proof/proof_2/status")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_prove_batch_empty() {
+        let client = SindriClient::default();
+        let results = client
+            .prove_batch(
+                "test_circuit_123",
+                Vec::<String>::new(),
+                BatchOptions::default(),
+            )
+            .await;
+        assert!(results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_prove_batch_retries_transient_status_errors() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/proof/proof_1/status"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(MAX_STATUS_FAILURES as u64 - 1)
+            .mount(&mock_server)
+            .await;
+        mount_proof(&mock_server, "x=1", "proof_1", JobStatus::Ready).await;
+
+        // Disable the retries of the middleware, so that status errors reach the batch
+        let client = SindriClient::builder()
+            .retry_budget(Duration::ZERO)
+            .build()
+            .unwrap()
+            .with_base_url(mock_server.uri());
+        let results = client
+            .prove_batch("test_circuit_123", ["x=1"], batch_options(1))
+            .await;
+        assert_eq!(results[0].as_ref().unwrap().proof_id, "proof_1");
+    }
+
+    #[tokio::test]
+    async fn test_prove_batch_cancelled() {
+        let mock_server = mock_batch_server().await;
+        let token = crate::CancellationToken::new();
+        token.cancel();
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+
+        let mut options = batch_options(1);
+        if let Some(polling_options) = options.polling_options.as_mut() {
+            polling_options.cancellation_token = Some(token);
+        }
+        let results = client
+            .prove_batch("test_circuit_123", ["x=1", "x=2"], options)
+            .await;
+        assert!(matches!(&results[0], Err(Error::Cancelled { id }) if id == "proof_1"));
+        assert!(matches!(results[1], Err(Error::NotSubmitted)));
+        assert_eq!(results[1].as_ref().unwrap_err().job_id(), None);
+    }
+}
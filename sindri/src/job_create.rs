@@ -1,16 +1,24 @@
 //! These methods will submit a project deploy or proof request to Sindri,
 //! without waiting for the job to complete.
 
-use std::{collections::HashMap, fs, path::Path};
+use std::{collections::HashMap, path::Path};
 
 use regex::Regex;
 use sindri_openapi::{
-    apis::circuits_api::{circuit_create, proof_create},
-    models::{CircuitInfoResponse, CircuitProveInput, ProofInfoResponse},
+    apis::circuits_api::proof_create,
+    models::{CircuitInfoResponse, CircuitProveInput, JobStatus, ProofInfoResponse},
 };
 use tracing::{debug, info};
 
-use crate::{client::SindriClient, types::ProofInput, utils::compress_directory};
+use crate::{
+    client::{CircuitListFilter, DedupeMode, SindriClient},
+    types::{CircuitInfo, PackagePlan, ProofInput},
+    utils::{
+        compress_directory, parse_api_datetime, plan_directory, ProjectArchive,
+        PROJECT_DIGEST_META_KEY,
+    },
+    Error,
+};
 
 #[cfg(feature = "rich-terminal")]
 use crate::utils::ClockProgressBar;
@@ -24,6 +32,15 @@ impl SindriClient {
     /// guest code with this method. Upon deployment, this method continuously polls the service to
     /// track the compilation status until the process either completes successfully or fails.
     ///
+    /// The project is streamed from disk rather than loaded into memory, and uploads which fail
+    /// with a transient error are retried. Upload progress can be tracked with
+    /// [`with_upload_progress`](Self::with_upload_progress). Project directories are packaged
+    /// according to the [`PackageOptions`](crate::client::PackageOptions) of the client, e.g.
+    /// to include hidden files or control how symbolic links are packaged.
+    ///
+    /// If the client is configured to dedupe deploys (see [`DedupeMode`]), an existing ready
+    /// circuit built from an identical project is returned instead of uploading the project.
+    ///
     /// # Arguments
     ///
     /// * `project` - Path to a local project directory or an archive file (.zip, .tar, .tar.gz, .tgz)
@@ -57,7 +74,7 @@ impl SindriClient {
         project: String,
         tags: Option<Vec<String>>,
         meta: Option<HashMap<String, String>>,
-    ) -> Result<CircuitInfoResponse, Box<dyn std::error::Error>> {
+    ) -> Result<CircuitInfoResponse, Error> {
         info!("Creating new circuit from project: {}", project);
         debug!("Circuit tags: {:?}, metadata: {:?}", tags, meta);
 
@@ -66,7 +83,7 @@ impl SindriClient {
         if let Some(ref tags) = tags {
             for tag in tags {
                 if !tag_rules.is_match(tag) {
-                    return Err(format!("\"{tag}\" is not a valid tag. Tags may only contain alphanumeric characters, underscores, hyphens, and periods.").into());
+                    return Err(Error::Validation(format!("\"{tag}\" is not a valid tag. Tags may only contain alphanumeric characters, underscores, hyphens, and periods.")));
                 }
             }
         }
@@ -80,24 +97,37 @@ impl SindriClient {
             .cyan()
         );
 
-        // Load the project into a byte array whether it is a compressed
-        // file already or a directory
-        let project_bytes = match Path::new(&project) {
-            p if p.is_dir() => {
-                info!("Compressing directory for upload");
-                compress_directory(p, None).await?
-            }
-            p if p.is_file() => {
-                let extension_regex = Regex::new(r"(?i)\.(zip|tar|tar\.gz|tgz)$")?;
-                if !extension_regex.is_match(&project) {
-                    return Err("Project is not a zip file or tarball".into());
-                }
-                #[cfg(feature = "rich-terminal")]
-                println!("{}", style("  ✓ Detected compressed project file").cyan());
-                fs::read(&project)?
-            }
-            _ => return Err("Project is not a file or directory".into()),
-        };
+        let archive = self.package_project(&project).await?;
+        if let Some(circuit) = self
+            .find_identical_circuit(&archive, tags.as_deref())
+            .await?
+        {
+            info!(
+                "Reusing circuit {} built from an identical project",
+                circuit.id()
+            );
+            #[cfg(feature = "rich-terminal")]
+            println!(
+                "{}",
+                style(format!(
+                    "  ✓ Reusing circuit {} built from an identical project",
+                    circuit.id()
+                ))
+                .cyan()
+            );
+            return Ok(circuit);
+        }
+
+        let mut meta = meta;
+        if self.deploy_options.attaches_project_digest() {
+            debug!(
+                "Attaching project digest {} to circuit metadata",
+                archive.digest()
+            );
+            meta.get_or_insert_with(HashMap::new)
+                .entry(PROJECT_DIGEST_META_KEY.to_string())
+                .or_insert_with(|| archive.digest().to_string());
+        }
 
         info!("Uploading circuit to Sindri");
         #[cfg(feature = "rich-terminal")]
@@ -106,7 +136,7 @@ impl SindriClient {
         #[cfg(feature = "rich-terminal")]
         let pb = ClockProgressBar::new("Sending files to circuit create endpoint...");
 
-        let response = circuit_create(&self.config, project_bytes, meta, tags).await?;
+        let response = self.upload_project(&archive, meta, tags).await?;
 
         #[cfg(feature = "rich-terminal")]
         pb.clear();
@@ -114,6 +144,143 @@ impl SindriClient {
         Ok(response)
     }
 
+    /// Prepares a local project for upload, without deploying it.
+    ///
+    /// Project directories are validated against the manifest schema and compressed into a
+    /// temporary tarball according to the [`PackageOptions`](crate::client::PackageOptions)
+    /// of the client, while archive files are used as is. This is the same preparation
+    /// performed by [`request_build`](Self::request_build), e.g. to inspect the project digest
+    /// before deploying.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - Path to a local project directory or an archive file (.zip, .tar, .tar.gz, .tgz)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use sindri::client::SindriClient;
+    ///
+    /// let client = SindriClient::default();
+    /// let archive = client.package_project("path/to/directory").await.unwrap();
+    /// println!("{} ({} bytes)", archive.digest(), archive.size());
+    /// # });
+    /// ```
+    pub async fn package_project(
+        &self,
+        project: impl AsRef<Path>,
+    ) -> Result<ProjectArchive, Error> {
+        // Locate the project archive on disk, compressing the project first
+        // if it is a directory
+        match project.as_ref() {
+            p if p.is_dir() => {
+                info!("Compressing directory for upload");
+                let manifest_schema = self.get_manifest_schema().await;
+                compress_directory(p, &manifest_schema, &self.package_options).await
+            }
+            p if p.is_file() => {
+                let extension_regex = Regex::new(r"(?i)\.(zip|tar|tar\.gz|tgz)$").unwrap();
+                if !extension_regex.is_match(&p.to_string_lossy()) {
+                    return Err(Error::Validation(
+                        "Project is not a zip file or tarball".to_string(),
+                    ));
+                }
+                #[cfg(feature = "rich-terminal")]
+                println!("{}", style("  ✓ Detected compressed project file").cyan());
+                ProjectArchive::from_file(p)
+            }
+            _ => Err(Error::Validation(
+                "Project is not a file or directory".to_string(),
+            )),
+        }
+    }
+
+    /// Reports what packaging a project directory would upload, without packaging it.
+    ///
+    /// Lists the files which would be included with their sizes, and the files and directories
+    /// which would be left out with the rule that excluded each of them: a pattern of an ignore
+    /// file such as `.sindriignore` or `.gitignore`, or the name being hidden. The included files
+    /// are compressed to project the archive size against the upload limit, but the manifest
+    /// is not validated. The report follows the [`PackageOptions`](crate::client::PackageOptions)
+    /// of the client, so it can be used to check options before deploying with them.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - Path to a local project directory
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sindri::client::SindriClient;
+    ///
+    /// let client = SindriClient::default();
+    /// let plan = client.plan_package("path/to/directory").unwrap();
+    /// for excluded in &plan.excluded {
+    ///     println!("{} excluded by {}", excluded.path.display(), excluded.reason);
+    /// }
+    /// println!("{} of {} bytes", plan.compressed_size, plan.max_size);
+    /// ```
+    pub fn plan_package(&self, dir: impl AsRef<Path>) -> Result<PackagePlan, Error> {
+        let dir = dir.as_ref();
+        if !dir.is_dir() {
+            return Err(Error::Validation("Project is not a directory".to_string()));
+        }
+        plan_directory(dir, &self.package_options)
+    }
+
+    /// Finds the most recent ready circuit built from the same project as `archive`,
+    /// if the client is configured to reuse such circuits.
+    async fn find_identical_circuit(
+        &self,
+        archive: &ProjectArchive,
+        tags: Option<&[String]>,
+    ) -> Result<Option<CircuitInfoResponse>, Error> {
+        let dedupe = self.deploy_options.dedupe;
+        if dedupe == DedupeMode::Off {
+            return Ok(None);
+        }
+
+        // Projects packaged from a directory name their project, which narrows the search
+        let circuits = match archive.manifest() {
+            Some(manifest) => {
+                let projects = self.list_projects().await?;
+                match projects
+                    .into_iter()
+                    .find(|project| project.name == manifest.name())
+                {
+                    Some(project) => self.list_project_circuits(&project.project_id).await?,
+                    // The project has never been deployed
+                    None => return Ok(None),
+                }
+            }
+            None => self.list_circuits(CircuitListFilter::default()).await?,
+        };
+        debug!(
+            "Searching {} circuits for project digest {}",
+            circuits.len(),
+            archive.digest()
+        );
+        let requested_tags = tags.unwrap_or_default();
+        Ok(circuits
+            .into_iter()
+            .filter(|circuit| *circuit.status() == JobStatus::Ready)
+            .filter(|circuit| {
+                circuit
+                    .meta()
+                    .get(PROJECT_DIGEST_META_KEY)
+                    .map(String::as_str)
+                    == Some(archive.digest())
+            })
+            .filter(|circuit| {
+                dedupe == DedupeMode::Reuse
+                    || requested_tags
+                        .iter()
+                        .all(|tag| circuit.tags().contains(tag))
+            })
+            .max_by_key(|circuit| parse_api_datetime(circuit.date_created())))
+    }
+
     /// Requests proof generation for a circuit (without waiting for job completion).
     ///
     /// This method initiates proof generation and automatically polls the Sindri API until the proof
@@ -124,14 +291,14 @@ impl SindriClient {
     ///
     /// * `circuit_id` - ID of the circuit to prove
     /// * `proof_input` - Input values for the proof. Can be provided as a JSON object, &str, or String.
-    ///                   The format (JSON, TOML, base64, etc.) should match your circuit's expected input structure.
+    ///   The format (JSON, TOML, base64, etc.) should match your circuit's expected input structure.
     /// * `meta` - Optional metadata key-value pairs
     /// * `verify` - Whether to verify the proof (server-side) after generation. A proof status
-    ///              of `Failed` would be returned if the proof is not valid.
+    ///   of `Failed` would be reported if the proof is not valid.
     /// * `prover_implementation` - Optional specific prover implementation to use.
-    ///                            This field is generally for internal development only.
-    ///                            Sindri automatically selects the most performant implementation
-    ///                            based on your project's deployment details.
+    ///   This field is generally for internal development only.
+    ///   Sindri automatically selects the most performant implementation
+    ///   based on your project's deployment details.
     ///
     /// # Returns
     ///
@@ -156,7 +323,7 @@ impl SindriClient {
         meta: Option<HashMap<String, String>>,
         verify: Option<bool>,
         prover_implementation: Option<String>,
-    ) -> Result<ProofInfoResponse, Box<dyn std::error::Error>> {
+    ) -> Result<ProofInfoResponse, Error> {
         info!("Creating proof for circuit: {}", circuit_id);
         debug!(
             "Proof metadata: {:?}, verify: {:?}, prover: {:?}",
@@ -179,7 +346,7 @@ impl SindriClient {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{types::CircuitInfo, BoojumCircuitInfoResponse};
+    use crate::{types::CircuitInfo, BoojumCircuitInfoResponse, ProjectInfoResponse};
     use wiremock::{
         matchers::{method, path},
         MockServer, ResponseTemplate,
@@ -231,6 +398,175 @@ mod tests {
         assert_eq!(circuit_response.id(), "test_circuit_123");
     }
 
+    #[tokio::test]
+    async fn test_request_build_attaches_project_digest() {
+        let mock_server = mock_server().await;
+        let client = SindriClient::default()
+            .with_base_url(mock_server.uri())
+            .with_project_digest_meta(true);
+        let temp_dir = tempfile::tempdir().unwrap();
+        let test_file = temp_dir.path().join("test.tar.gz");
+        std::fs::write(&test_file, "test content").unwrap();
+
+        let archive = client.package_project(&test_file).await.unwrap();
+        client
+            .request_build(test_file.to_str().unwrap().to_string(), None, None)
+            .await
+            .unwrap();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let body = String::from_utf8_lossy(&requests[0].body);
+        let expected = format!(
+            r#"{{"{}":"{}"}}"#,
+            PROJECT_DIGEST_META_KEY,
+            archive.digest()
+        );
+        assert!(body.contains(&expected), "{body}");
+    }
+
+    #[tokio::test]
+    async fn test_request_build_dedupe() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let test_file = temp_dir.path().join("test.tar.gz");
+        std::fs::write(&test_file, "test content").unwrap();
+        let project = test_file.to_str().unwrap().to_string();
+        let digest = ProjectArchive::from_file(&test_file)
+            .unwrap()
+            .digest()
+            .to_string();
+
+        let circuit = |id: &str, status: JobStatus, digest: &str, date: &str| {
+            CircuitInfoResponse::Boojum(Box::new(BoojumCircuitInfoResponse {
+                circuit_id: id.to_string(),
+                status,
+                tags: vec!["v1".to_string()],
+                meta: HashMap::from([(PROJECT_DIGEST_META_KEY.to_string(), digest.to_string())]),
+                date_created: date.to_string(),
+                ..Default::default()
+            }))
+        };
+        let mock_server = MockServer::start().await;
+        wiremock::Mock::given(method("GET"))
+            .and(path("/api/v1/circuit/list"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vec![
+                circuit("old", JobStatus::Ready, &digest, "2025-01-01T00:00:00Z"),
+                circuit("latest", JobStatus::Ready, &digest, "2025-02-01T00:00:00Z"),
+                circuit("failed", JobStatus::Failed, &digest, "2025-03-01T00:00:00Z"),
+                circuit(
+                    "other",
+                    JobStatus::Ready,
+                    "sha256:0",
+                    "2025-03-01T00:00:00Z",
+                ),
+            ]))
+            .mount(&mock_server)
+            .await;
+        wiremock::Mock::given(method("POST"))
+            .and(path("/api/v1/circuit/create"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(circuit(
+                "new",
+                JobStatus::Queued,
+                &digest,
+                "2025-04-01T00:00:00Z",
+            )))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+
+        // The most recent ready circuit is reused regardless of tags
+        let client = client.with_dedupe(DedupeMode::Reuse);
+        let tags = Some(vec!["v2".to_string()]);
+        let reused = client
+            .request_build(project.clone(), tags.clone(), None)
+            .await
+            .unwrap();
+        assert_eq!(reused.id(), "latest");
+
+        // Unless the requested tags must be applied, which requires a new circuit
+        let client = client.with_dedupe(DedupeMode::ReuseTagged);
+        let reused = client
+            .request_build(project.clone(), Some(vec!["v1".to_string()]), None)
+            .await
+            .unwrap();
+        assert_eq!(reused.id(), "latest");
+        let created = client.request_build(project, tags, None).await.unwrap();
+        assert_eq!(created.id(), "new");
+
+        // The new circuit records the digest so that it can be reused later. The record and
+        // replay features compress the upload, which hides the digest from this check.
+        #[cfg(not(any(feature = "record", feature = "replay")))]
+        {
+            let requests = mock_server.received_requests().await.unwrap();
+            let upload = requests.last().unwrap();
+            assert!(String::from_utf8_lossy(&upload.body).contains(&digest));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_build_dedupe_project_directory() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            temp_dir.path().join("sindri.json"),
+            r#"{"name": "demo", "circuitType": "circom"}"#,
+        )
+        .unwrap();
+        std::fs::write(temp_dir.path().join("circuit.circom"), "template Main() {}").unwrap();
+        let project = temp_dir.path().to_str().unwrap().to_string();
+
+        let mock_server = MockServer::start().await;
+        let client = SindriClient::default()
+            .with_base_url(mock_server.uri())
+            .with_dedupe(DedupeMode::Reuse);
+        let digest = client
+            .package_project(&project)
+            .await
+            .unwrap()
+            .digest()
+            .to_string();
+
+        wiremock::Mock::given(method("POST"))
+            .and(path("/api/v1/project/list"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vec![
+                ProjectInfoResponse {
+                    name: "other".to_string(),
+                    project_id: "project-456".to_string(),
+                    ..Default::default()
+                },
+                ProjectInfoResponse {
+                    name: "demo".to_string(),
+                    project_id: "project-123".to_string(),
+                    ..Default::default()
+                },
+            ]))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        // The circuits are listed by the ID of the project named in the manifest
+        wiremock::Mock::given(method("GET"))
+            .and(path("/api/v1/project/project-123/circuits"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vec![
+                CircuitInfoResponse::Boojum(Box::new(BoojumCircuitInfoResponse {
+                    circuit_id: "existing".to_string(),
+                    status: JobStatus::Ready,
+                    meta: HashMap::from([(PROJECT_DIGEST_META_KEY.to_string(), digest)]),
+                    ..Default::default()
+                })),
+            ]))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        wiremock::Mock::given(method("POST"))
+            .and(path("/api/v1/circuit/create"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let reused = client.request_build(project, None, None).await.unwrap();
+        assert_eq!(reused.id(), "existing");
+    }
+
     #[tokio::test]
     async fn test_request_proof() {
         let mock_server = mock_server().await;
@@ -0,0 +1,417 @@
+//! These methods wait for a previously submitted circuit or proof to finish processing,
+//! for instance after resuming from a job ID that was persisted before a restart.
+
+use std::time::{Duration, Instant};
+
+use sindri_openapi::{
+    apis::{circuit_status, circuits_api::circuit_detail, proof_status, proofs_api::proof_detail},
+    models::{CircuitInfoResponse, JobStatus, ProofInfoResponse},
+};
+use tracing::{debug, info, warn};
+
+use crate::{
+    client::{PollingOptions, PollingStrategy, SindriClient},
+    types::CircuitInfo,
+    Error,
+};
+
+#[cfg(feature = "rich-terminal")]
+use crate::utils::ClockProgressBar;
+
+/// The kinds of long-running jobs that can be polled on Sindri.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum JobKind {
+    Circuit,
+    Proof,
+}
+
+impl JobKind {
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            JobKind::Circuit => "circuit",
+            JobKind::Proof => "proof",
+        }
+    }
+
+    fn operation(&self) -> &'static str {
+        match self {
+            JobKind::Circuit => "Circuit compilation",
+            JobKind::Proof => "Proof generation",
+        }
+    }
+}
+
+impl SindriClient {
+    /// Waits for a circuit to finish compiling.
+    ///
+    /// This method polls the Sindri API until the circuit is either ready or has failed.
+    /// It is useful to resume waiting on a circuit submitted with
+    /// [`request_build`](Self::request_build), e.g. after a restart.
+    ///
+    /// # Arguments
+    ///
+    /// * `circuit_id` - ID of the circuit to wait for
+    /// * `polling_options` - Polling configuration for this wait, which may differ from the client's
+    ///
+    /// # Returns
+    ///
+    /// Returns circuit information on successful compilation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::JobFailed`] if compilation fails, [`Error::Timeout`] if compilation
+    /// does not finish within the polling timeout, and [`Error::Cancelled`] if the
+    /// cancellation token is cancelled while waiting.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use sindri::client::SindriClient;
+    ///
+    /// let client = SindriClient::default();
+    /// let circuit_id = "uuid-assigned-during-circuit-creation";
+    /// let circuit = client
+    ///     .wait_for_circuit(circuit_id, client.polling_options.clone())
+    ///     .await
+    ///     .unwrap();
+    /// # });
+    /// ```
+    pub async fn wait_for_circuit(
+        &self,
+        circuit_id: &str,
+        polling_options: PollingOptions,
+    ) -> Result<CircuitInfoResponse, Error> {
+        let status = self
+            .wait_for_job(JobKind::Circuit, circuit_id, &polling_options)
+            .await?;
+
+        let circuit_info = circuit_detail(&self.config, circuit_id, None).await?;
+        if status == JobStatus::Failed {
+            return Err(Error::JobFailed {
+                id: circuit_id.to_string(),
+                message: circuit_info.error(),
+            });
+        }
+        Ok(circuit_info)
+    }
+
+    /// Waits for a proof to finish generating.
+    ///
+    /// This method polls the Sindri API until the proof is either ready or has failed.
+    /// It is useful to resume waiting on a proof submitted with
+    /// [`request_proof`](Self::request_proof), e.g. after a restart.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof_id` - ID of the proof to wait for
+    /// * `polling_options` - Polling configuration for this wait, which may differ from the client's
+    ///
+    /// # Returns
+    ///
+    /// Returns proof information on successful generation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::JobFailed`] if proof generation (or server-side verification) fails,
+    /// [`Error::Timeout`] if the proof does not finish within the polling timeout,
+    /// and [`Error::Cancelled`] if the cancellation token is cancelled while waiting.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use sindri::client::{PollingOptions, SindriClient};
+    /// use std::time::Duration;
+    ///
+    /// let client = SindriClient::default();
+    /// let proof_id = "uuid-assigned-during-proof-generation";
+    /// let polling_options = PollingOptions {
+    ///     timeout: Some(Duration::from_secs(3600)),
+    ///     ..Default::default()
+    /// };
+    /// let proof = client.wait_for_proof(proof_id, polling_options).await.unwrap();
+    /// # });
+    /// ```
+    pub async fn wait_for_proof(
+        &self,
+        proof_id: &str,
+        polling_options: PollingOptions,
+    ) -> Result<ProofInfoResponse, Error> {
+        let status = self
+            .wait_for_job(JobKind::Proof, proof_id, &polling_options)
+            .await?;
+        self.finished_proof(proof_id, status).await
+    }
+
+    /// Fetches the details of a proof which has finished processing with the given status.
+    pub(crate) async fn finished_proof(
+        &self,
+        proof_id: &str,
+        status: JobStatus,
+    ) -> Result<ProofInfoResponse, Error> {
+        let proof_info = proof_detail(&self.config, proof_id, None, None, None, None).await?;
+        if status == JobStatus::Failed {
+            return Err(Error::JobFailed {
+                id: proof_id.to_string(),
+                message: proof_info.error.clone().flatten(),
+            });
+        }
+        Ok(proof_info)
+    }
+
+    /// Fetches the current status of a job.
+    pub(crate) async fn job_status(&self, kind: JobKind, id: &str) -> Result<JobStatus, Error> {
+        let status = match kind {
+            JobKind::Circuit => circuit_status(&self.config, id).await?.status,
+            JobKind::Proof => proof_status(&self.config, id).await?.status,
+        };
+        Ok(status)
+    }
+
+    /// Fetches the status of a job, along with its age in seconds if `with_timing` is set.
+    async fn poll_job(
+        &self,
+        kind: JobKind,
+        id: &str,
+        with_timing: bool,
+    ) -> Result<(JobStatus, Option<f64>), Error> {
+        if with_timing {
+            let snapshot = self.job_snapshot(kind, id).await?;
+            Ok((snapshot.status, snapshot.job_age_sec()))
+        } else {
+            Ok((self.job_status(kind, id).await?, None))
+        }
+    }
+
+    /// Polls a job until it reaches a terminal status, which is returned.
+    ///
+    /// This is the single polling engine shared by all waiting methods.
+    pub(crate) async fn wait_for_job(
+        &self,
+        kind: JobKind,
+        id: &str,
+        polling_options: &PollingOptions,
+    ) -> Result<JobStatus, Error> {
+        // Only the adaptive strategy needs the job's timing, which requires the heavier
+        // detail endpoint instead of the status endpoint
+        let with_timing = matches!(polling_options.strategy, PollingStrategy::Adaptive { .. });
+
+        let start_time = Instant::now();
+        let mut attempt = 1;
+        let (mut status, mut job_age_sec) = self.poll_job(kind, id, with_timing).await?;
+        debug!("Initial {} status: {:?}", kind.name(), status);
+
+        #[cfg(feature = "rich-terminal")]
+        let pb = ClockProgressBar::new(&format!("Job status: {}", status));
+
+        while !matches!(status, JobStatus::Ready | JobStatus::Failed) {
+            if let Some(timeout) = polling_options.timeout {
+                if start_time.elapsed() > timeout {
+                    warn!("{} timed out after {:?}", kind.operation(), timeout);
+                    return Err(Error::Timeout {
+                        id: id.to_string(),
+                        timeout,
+                    });
+                }
+            }
+            let interval = polling_options.next_interval(attempt, job_age_sec);
+            poll_interval(id, interval, polling_options).await?;
+
+            let previous_status = status;
+            (status, job_age_sec) = self.poll_job(kind, id, with_timing).await?;
+            attempt += 1;
+            if status != previous_status {
+                debug!("{} {} status changed to {:?}", kind.name(), id, status);
+                #[cfg(feature = "rich-terminal")]
+                pb.update_message(&format!("Job status: {}", status));
+            }
+        }
+
+        match status {
+            JobStatus::Ready => info!(
+                "{} completed successfully after {:?}",
+                kind.operation(),
+                start_time.elapsed()
+            ),
+            _ => warn!(
+                "{} failed after {:?}",
+                kind.operation(),
+                start_time.elapsed()
+            ),
+        }
+
+        Ok(status)
+    }
+}
+
+/// Waits for one polling interval without blocking the runtime.
+///
+/// Returns [`Error::Cancelled`] if the cancellation token fires first.
+pub(crate) async fn poll_interval(
+    job_id: &str,
+    interval: Duration,
+    polling_options: &PollingOptions,
+) -> Result<(), Error> {
+    let sleep = tokio::time::sleep(interval);
+    match &polling_options.cancellation_token {
+        Some(token) => tokio::select! {
+            _ = sleep => Ok(()),
+            _ = token.cancelled() => {
+                warn!("Stopped waiting for job {} after cancellation", job_id);
+                Err(Error::Cancelled {
+                    id: job_id.to_string(),
+                })
+            }
+        },
+        None => {
+            sleep.await;
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    async fn mock_proof_server(final_status: JobStatus) -> MockServer {
+        let mock_server = MockServer::start().await;
+        // The first poll reports a queued proof, subsequent polls the final status
+        Mock::given(method("GET"))
+            .and(path("/api/v1/proof/test_proof_123/status"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(ProofInfoResponse {
+                status: JobStatus::Queued,
+                ..Default::default()
+            }))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/proof/test_proof_123/status"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(ProofInfoResponse {
+                status: final_status,
+                ..Default::default()
+            }))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/proof/test_proof_123/detail"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(ProofInfoResponse {
+                proof_id: "test_proof_123".to_string(),
+                status: final_status,
+                error: Some(Some("prover crashed".to_string())),
+                ..Default::default()
+            }))
+            .mount(&mock_server)
+            .await;
+        mock_server
+    }
+
+    fn fast_polling() -> PollingOptions {
+        PollingOptions {
+            interval: Duration::from_millis(10),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_proof_ready() {
+        let mock_server = mock_proof_server(JobStatus::Ready).await;
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+
+        let proof = client
+            .wait_for_proof("test_proof_123", fast_polling())
+            .await
+            .unwrap();
+        assert_eq!(proof.proof_id, "test_proof_123");
+        assert_eq!(proof.status, JobStatus::Ready);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_proof_failed() {
+        let mock_server = mock_proof_server(JobStatus::Failed).await;
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+
+        let err = client
+            .wait_for_proof("test_proof_123", fast_polling())
+            .await
+            .unwrap_err();
+        match err {
+            Error::JobFailed { id, message } => {
+                assert_eq!(id, "test_proof_123");
+                assert_eq!(message.as_deref(), Some("prover crashed"));
+            }
+            e => panic!("Expected JobFailed, got {:?}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_proof_adaptive() {
+        // The adaptive strategy polls the detail endpoint, which reports the job's timing
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/proof/test_proof_123/detail"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(ProofInfoResponse {
+                proof_id: "test_proof_123".to_string(),
+                status: JobStatus::InProgress,
+                queue_time_sec: Some(Some(0.001)),
+                ..Default::default()
+            }))
+            .up_to_n_times(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/proof/test_proof_123/detail"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(ProofInfoResponse {
+                proof_id: "test_proof_123".to_string(),
+                status: JobStatus::Ready,
+                ..Default::default()
+            }))
+            .mount(&mock_server)
+            .await;
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+
+        let polling_options = PollingOptions {
+            strategy: PollingStrategy::Adaptive {
+                fraction: 0.5,
+                max_interval: Duration::from_millis(50),
+            },
+            ..fast_polling()
+        };
+        let proof = client
+            .wait_for_proof("test_proof_123", polling_options)
+            .await
+            .unwrap();
+        assert_eq!(proof.status, JobStatus::Ready);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_job_timeout() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/proof/test_proof_123/status"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(ProofInfoResponse {
+                status: JobStatus::InProgress,
+                ..Default::default()
+            }))
+            .mount(&mock_server)
+            .await;
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+
+        let polling_options = PollingOptions {
+            timeout: Some(Duration::from_millis(50)),
+            ..fast_polling()
+        };
+        let err = client
+            .wait_for_proof("test_proof_123", polling_options)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Timeout { .. }));
+        assert_eq!(err.job_id(), Some("test_proof_123"));
+    }
+}
@@ -0,0 +1,301 @@
+//! These methods expose the progress of a circuit or proof as an async stream of
+//! status transitions, e.g. to drive a dashboard or custom progress reporting.
+
+use std::time::Instant;
+
+use chrono::Utc;
+use futures::{stream, Stream};
+use sindri_openapi::{
+    apis::{circuits_api::circuit_detail, proofs_api::proof_detail},
+    models::JobStatus,
+};
+use tracing::{debug, warn};
+
+use crate::{
+    client::{PollingOptions, SindriClient},
+    job_wait::{poll_interval, JobKind},
+    types::{CircuitInfo, JobStatusUpdate},
+    Error,
+};
+
+struct WatchState {
+    id: String,
+    polling_options: PollingOptions,
+    start_time: Instant,
+    last_status: Option<JobStatus>,
+    job_age_sec: Option<f64>,
+    attempt: u32,
+    finished: bool,
+}
+
+impl SindriClient {
+    /// Watches a circuit's compilation as a stream of status transitions.
+    ///
+    /// The stream polls the Sindri API according to the client's `polling_options` and yields
+    /// one [`JobStatusUpdate`] whenever the status changes, starting with the current status.
+    /// It ends after the circuit is ready or has failed, or after yielding an error (including
+    /// [`Error::Timeout`] and [`Error::Cancelled`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `circuit_id` - ID of the circuit to watch
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use futures::StreamExt;
+    /// use sindri::client::SindriClient;
+    ///
+    /// let client = SindriClient::default();
+    /// let mut updates = std::pin::pin!(client.watch_circuit("team_name/project_name:tag"));
+    /// while let Some(update) = updates.next().await {
+    ///     let update = update.unwrap();
+    ///     println!("{}: {}", update.timestamp, update.status);
+    /// }
+    /// # });
+    /// ```
+    pub fn watch_circuit(
+        &self,
+        circuit_id: &str,
+    ) -> impl Stream<Item = Result<JobStatusUpdate, Error>> + '_ {
+        self.watch_job(JobKind::Circuit, circuit_id)
+    }
+
+    /// Watches a proof's generation as a stream of status transitions.
+    ///
+    /// The stream polls the Sindri API according to the client's `polling_options` and yields
+    /// one [`JobStatusUpdate`] whenever the status changes, starting with the current status.
+    /// It ends after the proof is ready or has failed, or after yielding an error (including
+    /// [`Error::Timeout`] and [`Error::Cancelled`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `proof_id` - ID of the proof to watch
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use futures::StreamExt;
+    /// use sindri::client::SindriClient;
+    ///
+    /// let client = SindriClient::default();
+    /// let proof_id = "uuid-assigned-during-proof-generation";
+    /// let mut updates = std::pin::pin!(client.watch_proof(proof_id));
+    /// while let Some(update) = updates.next().await {
+    ///     let update = update.unwrap();
+    ///     println!(
+    ///         "{} (queued {:?}s, computing {:?}s)",
+    ///         update.status, update.queue_time_sec, update.compute_time_sec
+    ///     );
+    /// }
+    /// # });
+    /// ```
+    pub fn watch_proof(
+        &self,
+        proof_id: &str,
+    ) -> impl Stream<Item = Result<JobStatusUpdate, Error>> + '_ {
+        self.watch_job(JobKind::Proof, proof_id)
+    }
+
+    /// Fetches the current status and timing of a job from its detail endpoint.
+    ///
+    /// Large payloads like proofs and verification keys are excluded from the response.
+    pub(crate) async fn job_snapshot(
+        &self,
+        kind: JobKind,
+        id: &str,
+    ) -> Result<JobStatusUpdate, Error> {
+        let update = match kind {
+            JobKind::Circuit => {
+                let circuit = circuit_detail(&self.config, id, Some(false)).await?;
+                JobStatusUpdate {
+                    id: circuit.id().to_string(),
+                    status: *circuit.status(),
+                    timestamp: Utc::now(),
+                    queue_time_sec: circuit.queue_time_sec(),
+                    compute_time_sec: circuit.compute_time_sec(),
+                }
+            }
+            JobKind::Proof => {
+                let proof = proof_detail(
+                    &self.config,
+                    id,
+                    Some(false),
+                    Some(false),
+                    Some(false),
+                    Some(false),
+                )
+                .await?;
+                JobStatusUpdate {
+                    id: proof.proof_id,
+                    status: proof.status,
+                    timestamp: Utc::now(),
+                    queue_time_sec: proof.queue_time_sec.flatten(),
+                    compute_time_sec: proof.compute_time_sec.flatten(),
+                }
+            }
+        };
+        Ok(update)
+    }
+
+    fn watch_job(
+        &self,
+        kind: JobKind,
+        id: &str,
+    ) -> impl Stream<Item = Result<JobStatusUpdate, Error>> + '_ {
+        let state = WatchState {
+            id: id.to_string(),
+            polling_options: self.polling_options.clone(),
+            start_time: Instant::now(),
+            last_status: None,
+            job_age_sec: None,
+            attempt: 0,
+            finished: false,
+        };
+
+        stream::unfold(state, move |mut state| async move {
+            if state.finished {
+                return None;
+            }
+            loop {
+                // Wait between polls, but report the current status right away
+                if state.last_status.is_some() {
+                    if let Some(timeout) = state.polling_options.timeout {
+                        if state.start_time.elapsed() > timeout {
+                            warn!(
+                                "Stopped watching {} {} after {:?}",
+                                kind.name(),
+                                state.id,
+                                timeout
+                            );
+                            state.finished = true;
+                            let error = Error::Timeout {
+                                id: state.id.clone(),
+                                timeout,
+                            };
+                            return Some((Err(error), state));
+                        }
+                    }
+                    let interval = state
+                        .polling_options
+                        .next_interval(state.attempt, state.job_age_sec);
+                    if let Err(error) =
+                        poll_interval(&state.id, interval, &state.polling_options).await
+                    {
+                        state.finished = true;
+                        return Some((Err(error), state));
+                    }
+                }
+
+                let snapshot = self.job_snapshot(kind, &state.id).await;
+                state.attempt += 1;
+                if let Ok(update) = &snapshot {
+                    state.job_age_sec = update.job_age_sec();
+                }
+                match snapshot {
+                    Ok(update) if state.last_status == Some(update.status) => continue,
+                    Ok(update) => {
+                        debug!(
+                            "{} {} status changed to {:?}",
+                            kind.name(),
+                            state.id,
+                            update.status
+                        );
+                        state.last_status = Some(update.status);
+                        state.finished = update.is_finished();
+                        return Some((Ok(update), state));
+                    }
+                    Err(error) => {
+                        state.finished = true;
+                        return Some((Err(error), state));
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProofInfoResponse;
+    use futures::StreamExt;
+    use std::time::Duration;
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    async fn mount_proof_detail(
+        mock_server: &MockServer,
+        status: JobStatus,
+        compute_time_sec: Option<f64>,
+        times: u64,
+    ) {
+        Mock::given(method("GET"))
+            .and(path("/api/v1/proof/test_proof_123/detail"))
+            .and(query_param("include_proof", "false"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(ProofInfoResponse {
+                proof_id: "test_proof_123".to_string(),
+                status,
+                queue_time_sec: Some(Some(2.0)),
+                compute_time_sec: Some(compute_time_sec),
+                ..Default::default()
+            }))
+            .up_to_n_times(times)
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_watch_proof_transitions() {
+        let mock_server = MockServer::start().await;
+        mount_proof_detail(&mock_server, JobStatus::Queued, None, 1).await;
+        mount_proof_detail(&mock_server, JobStatus::InProgress, Some(1.0), 2).await;
+        mount_proof_detail(&mock_server, JobStatus::Ready, Some(5.0), 1).await;
+
+        let client = SindriClient::default()
+            .with_base_url(mock_server.uri())
+            .with_polling_interval(Duration::from_millis(10));
+        let updates: Vec<_> = client.watch_proof("test_proof_123").collect().await;
+
+        // Repeated statuses are collapsed into a single transition
+        let statuses: Vec<_> = updates
+            .iter()
+            .map(|update| update.as_ref().unwrap().status)
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![JobStatus::Queued, JobStatus::InProgress, JobStatus::Ready]
+        );
+
+        let last = updates.last().unwrap().as_ref().unwrap();
+        assert_eq!(last.id, "test_proof_123");
+        assert_eq!(last.queue_time_sec, Some(2.0));
+        assert_eq!(last.compute_time_sec, Some(5.0));
+        assert!(last.is_finished());
+        let first = updates.first().unwrap().as_ref().unwrap();
+        assert!(first.timestamp <= last.timestamp);
+    }
+
+    #[tokio::test]
+    async fn test_watch_proof_ends_after_error() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/proof/test_proof_123/detail"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+        let updates: Vec<_> = client.watch_proof("test_proof_123").collect().await;
+
+        assert_eq!(updates.len(), 1);
+        assert_eq!(
+            updates[0].as_ref().unwrap_err().status(),
+            Some(crate::StatusCode::NOT_FOUND)
+        );
+    }
+}
@@ -2,16 +2,206 @@
 //!
 //! These methods should be used with caution outside of the CLI since there are
 //! no intentional checks in place to ensure the token is valid or has not expired.
+//! Long-running tools should use a [`JwtSession`] instead, which verifies and refreshes
+//! its access token as needed.
 
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use base64::engine::{general_purpose, Engine};
+use chrono::{DateTime, TimeDelta, Utc};
 use sindri_openapi::{
     apis::{
-        authorization_api::apikey_generate, configuration::Configuration,
-        token_api::jwt_token_generate, user_me,
+        authorization_api::apikey_generate,
+        configuration::Configuration,
+        token_api::{jwt_token_generate, jwt_token_refresh, jwt_token_verify},
+        user_me,
+    },
+    models::{
+        ObtainApikeyInput, TeamDetail, TokenObtainPairInputSchema, TokenRefreshInputSchema,
+        TokenVerifyInputSchema,
     },
-    models::{ObtainApikeyInput, TeamDetail, TokenObtainPairInputSchema},
 };
+use tokio::sync::Mutex;
+use tracing::debug;
+
+use crate::{api_keys::revealed_secret, client::SindriClient, Error, StatusCode};
+
+/// How long before its expiry an access token is refreshed.
+const REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// The tokens of a [`JwtSession`], guarded together so that only one refresh runs at a time.
+struct SessionTokens {
+    access: String,
+    refresh: String,
+    /// Expiry of the access token, if it could be read from the token's claims
+    expires_at: Option<DateTime<Utc>>,
+    /// When the access token was last verified, for tokens without a readable expiry
+    verified_at: Option<Instant>,
+}
+
+impl SessionTokens {
+    fn new(access: String, refresh: String) -> Self {
+        let expires_at = token_expiry(&access);
+        Self {
+            access,
+            refresh,
+            expires_at,
+            verified_at: None,
+        }
+    }
+}
+
+/// Reads the `exp` claim of a JWT without validating its signature.
+fn token_expiry(token: &str) -> Option<DateTime<Utc>> {
+    let payload = token.split('.').nth(1)?;
+    let claims = general_purpose::URL_SAFE_NO_PAD
+        .decode(payload.trim_end_matches('='))
+        .ok()?;
+    let claims: serde_json::Value = serde_json::from_slice(&claims).ok()?;
+    DateTime::from_timestamp(claims.get("exp")?.as_i64()?, 0)
+}
+
+/// A user session authenticated with a JWT access and refresh token pair.
+///
+/// The session verifies its access token with the Sindri API and refreshes it shortly
+/// before it expires, so that it can authenticate a [`SindriClient`] for an unbounded
+/// amount of time. Clones share the same tokens.
+///
+/// # Examples
+///
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// use sindri::client::SindriClient;
+///
+/// let session = SindriClient::default()
+///     .jwt_session_generate("username", "password")
+///     .await
+///     .unwrap();
+/// let client = SindriClient::builder().jwt_session(session).build().unwrap();
+/// let team = client.get_current_team().await.unwrap();
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct JwtSession {
+    config: Configuration,
+    tokens: Arc<Mutex<SessionTokens>>,
+}
 
-use crate::client::SindriClient;
+impl std::fmt::Debug for JwtSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print the tokens themselves
+        f.debug_struct("JwtSession")
+            .field("base_path", &self.config.base_path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl JwtSession {
+    /// Resumes a session from a previously obtained token pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Client whose base URL and HTTP stack are used to verify and refresh tokens
+    /// * `access` - JWT access token
+    /// * `refresh` - JWT refresh token
+    pub fn from_tokens(
+        client: &SindriClient,
+        access: impl Into<String>,
+        refresh: impl Into<String>,
+    ) -> Self {
+        let config = Configuration {
+            bearer_access_token: None,
+            ..client.config.clone()
+        };
+        Self {
+            config,
+            tokens: Arc::new(Mutex::new(SessionTokens::new(
+                access.into(),
+                refresh.into(),
+            ))),
+        }
+    }
+
+    /// Returns a valid access token, refreshing it first if it is about to expire.
+    ///
+    /// Tokens whose expiry cannot be read are verified with the Sindri API instead,
+    /// at most once per minute.
+    pub async fn access_token(&self) -> Result<String, Error> {
+        let mut tokens = self.tokens.lock().await;
+        match tokens.expires_at {
+            Some(expires_at) => {
+                if expires_at - Utc::now() <= TimeDelta::from_std(REFRESH_MARGIN).unwrap() {
+                    self.refresh_tokens(&mut tokens).await?;
+                }
+            }
+            None => {
+                if tokens
+                    .verified_at
+                    .is_none_or(|verified_at| verified_at.elapsed() > REFRESH_MARGIN)
+                {
+                    if !self.verify_token(&tokens.access).await? {
+                        self.refresh_tokens(&mut tokens).await?;
+                    }
+                    tokens.verified_at = Some(Instant::now());
+                }
+            }
+        }
+        Ok(tokens.access.clone())
+    }
+
+    /// Returns the current refresh token, e.g. to persist the session.
+    pub async fn refresh_token(&self) -> String {
+        self.tokens.lock().await.refresh.clone()
+    }
+
+    /// Checks the current access token with the Sindri API.
+    ///
+    /// Returns `false` if the token is invalid or has expired.
+    pub async fn verify(&self) -> Result<bool, Error> {
+        let access = self.tokens.lock().await.access.clone();
+        self.verify_token(&access).await
+    }
+
+    /// Exchanges the refresh token for a new access token, regardless of the current
+    /// token's expiry.
+    pub async fn refresh(&self) -> Result<(), Error> {
+        let mut tokens = self.tokens.lock().await;
+        self.refresh_tokens(&mut tokens).await
+    }
+
+    async fn verify_token(&self, token: &str) -> Result<bool, Error> {
+        let input = TokenVerifyInputSchema {
+            token: token.to_string(),
+        };
+        match jwt_token_verify(&self.config, input)
+            .await
+            .map_err(Error::from)
+        {
+            Ok(_) => Ok(true),
+            Err(e) if e.status() == Some(StatusCode::UNAUTHORIZED) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn refresh_tokens(&self, tokens: &mut SessionTokens) -> Result<(), Error> {
+        debug!("Refreshing JWT access token");
+        let input = TokenRefreshInputSchema {
+            refresh: tokens.refresh.clone(),
+        };
+        let response = jwt_token_refresh(&self.config, input).await?;
+        let Some(access) = response.access else {
+            return Err(Error::UnexpectedResponse(
+                "token refresh returned no access token".to_string(),
+            ));
+        };
+        *tokens = SessionTokens::new(access, response.refresh);
+        tokens.verified_at = Some(Instant::now());
+        Ok(())
+    }
+}
 
 impl SindriClient {
     /// Generate and return a JWT token from a username and password
@@ -21,7 +211,7 @@ impl SindriClient {
         &self,
         username: &str,
         password: &str,
-    ) -> Result<String, Box<dyn std::error::Error>> {
+    ) -> Result<String, Error> {
         let token = jwt_token_generate(
             &self.config,
             TokenObtainPairInputSchema {
@@ -34,14 +224,32 @@ impl SindriClient {
         Ok(token.access)
     }
 
+    /// Starts a [`JwtSession`] from a username and password
+    ///
+    /// Unlike [`jwt_token_generate`](Self::jwt_token_generate), the session keeps the
+    /// refresh token and renews its access token before it expires.
+    pub async fn jwt_session_generate(
+        &self,
+        username: &str,
+        password: &str,
+    ) -> Result<JwtSession, Error> {
+        let token = jwt_token_generate(
+            &self.config,
+            TokenObtainPairInputSchema {
+                username: username.to_string(),
+                password: password.to_string(),
+            },
+        )
+        .await?;
+
+        Ok(JwtSession::from_tokens(self, token.access, token.refresh))
+    }
+
     /// Get the teams for a user
     ///
     /// Any API key attached to the SindriClient is replaced with
     /// the input JWT token.
-    pub async fn teams_jwt_auth(
-        &self,
-        token: &str,
-    ) -> Result<Vec<TeamDetail>, Box<dyn std::error::Error>> {
+    pub async fn teams_jwt_auth(&self, token: &str) -> Result<Vec<TeamDetail>, Error> {
         let config = Configuration {
             base_path: self.config.base_path.clone(),
             client: self.config.client.clone(),
@@ -63,7 +271,7 @@ impl SindriClient {
         password: &str,
         key_name: &str,
         team_id: &str,
-    ) -> Result<String, Box<dyn std::error::Error>> {
+    ) -> Result<String, Error> {
         let api_key = apikey_generate(
             &self.config,
             ObtainApikeyInput {
@@ -75,23 +283,31 @@ impl SindriClient {
         )
         .await?;
 
-        if let Some(api_key) = api_key.api_key {
-            Ok(api_key)
-        } else {
-            Err("Failed to generate API key".into())
-        }
+        Ok(revealed_secret(&api_key)?.to_string())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use sindri_openapi::models::{ApiKeyResponse, TokenObtainPairOutputSchema, UserMeResponse};
+    use sindri_openapi::models::{
+        ApiKeyResponse, TeamMeResponse, TokenObtainPairOutputSchema, TokenRefreshOutputSchema,
+        UserMeResponse,
+    };
     use wiremock::{
-        matchers::{method, path},
+        matchers::{body_json, header, method, path},
         Mock, MockServer, ResponseTemplate,
     };
 
+    /// Builds an unsigned JWT whose access expires at the given time.
+    fn jwt_expiring_at(name: &str, expires_at: DateTime<Utc>) -> String {
+        let claims = serde_json::json!({ "name": name, "exp": expires_at.timestamp() });
+        format!(
+            "e30.{}.signature",
+            general_purpose::URL_SAFE_NO_PAD.encode(claims.to_string())
+        )
+    }
+
     async fn mock_auth_server() -> MockServer {
         let mock_server = MockServer::start().await;
 
@@ -174,4 +390,142 @@ mod tests {
 
         assert_eq!(api_key, "test_api_key_123");
     }
+
+    #[test]
+    fn test_token_expiry() {
+        let expires_at = DateTime::from_timestamp(1_900_000_000, 0).unwrap();
+        assert_eq!(
+            token_expiry(&jwt_expiring_at("access", expires_at)),
+            Some(expires_at)
+        );
+        assert_eq!(token_expiry("not-a-jwt"), None);
+    }
+
+    #[tokio::test]
+    async fn test_jwt_session_refreshes_expired_token() {
+        let mock_server = MockServer::start().await;
+        let expired = jwt_expiring_at("old", Utc::now() - TimeDelta::hours(1));
+        let fresh = jwt_expiring_at("new", Utc::now() + TimeDelta::hours(1));
+
+        Mock::given(method("POST"))
+            .and(path("/api/token/refresh"))
+            .and(body_json(serde_json::json!({ "refresh": "refresh_1" })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(TokenRefreshOutputSchema {
+                    access: Some(fresh.clone()),
+                    refresh: "refresh_2".to_string(),
+                }),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/team/me"))
+            .and(header(
+                "authorization",
+                format!("Bearer {}", fresh).as_str(),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(TeamMeResponse::default()))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+        let session = JwtSession::from_tokens(&client, expired, "refresh_1");
+        let client = SindriClient::builder()
+            .auth_options(crate::client::AuthOptions {
+                api_key: Some("ignored_api_key".to_string()),
+                base_url: Some(mock_server.uri()),
+            })
+            .jwt_session(session.clone())
+            .build()
+            .unwrap();
+
+        // The token is refreshed once, then reused while it is valid
+        client.get_current_team().await.unwrap();
+        client.get_current_team().await.unwrap();
+        assert_eq!(session.refresh_token().await, "refresh_2");
+    }
+
+    #[tokio::test]
+    async fn test_jwt_session_retries_large_unauthorized_request() {
+        let mock_server = MockServer::start().await;
+        let revoked = jwt_expiring_at("old", Utc::now() + TimeDelta::hours(1));
+        let fresh = jwt_expiring_at("new", Utc::now() + TimeDelta::hours(1));
+
+        Mock::given(method("POST"))
+            .and(path("/api/token/refresh"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(TokenRefreshOutputSchema {
+                    access: Some(fresh.clone()),
+                    refresh: "refresh_2".to_string(),
+                }),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/team/settings"))
+            .and(header(
+                "authorization",
+                format!("Bearer {}", revoked).as_str(),
+            ))
+            .respond_with(ResponseTemplate::new(401))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/team/settings"))
+            .and(header(
+                "authorization",
+                format!("Bearer {}", fresh).as_str(),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(TeamDetail::default()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+        let session = JwtSession::from_tokens(&client, revoked, "refresh_1");
+        let client = SindriClient::builder()
+            .auth_options(crate::client::AuthOptions {
+                api_key: None,
+                base_url: Some(mock_server.uri()),
+            })
+            .jwt_session(session)
+            .build()
+            .unwrap();
+
+        // A body this large is compressed into a stream, which must not prevent the retry
+        let settings = crate::teams::TeamSettings {
+            display_name: Some("x".repeat(4096)),
+            ..Default::default()
+        };
+        client.update_team_settings(settings).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_jwt_session_verify() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/token/verify"))
+            .and(body_json(serde_json::json!({ "token": "valid" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/token/verify"))
+            .and(body_json(serde_json::json!({ "token": "revoked" })))
+            .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+                "detail": "Token is invalid or expired"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+        let valid = JwtSession::from_tokens(&client, "valid", "refresh");
+        assert!(valid.verify().await.unwrap());
+        let revoked = JwtSession::from_tokens(&client, "revoked", "refresh");
+        assert!(!revoked.verify().await.unwrap());
+    }
 }
@@ -47,6 +47,20 @@
 //!   [`create_circuit`](client::SindriClient::create_circuit) and
 //!   [`prove_circuit`](client::SindriClient::prove_circuit)
 //!
+//! - **Discovery**: Find existing circuits by project, tags, status or type with
+//!   [`list_circuits`](client::SindriClient::list_circuits), and page through proof history
+//!   with [`proofs`](client::SindriClient::proofs)
+//!
+//! - **Job Tracking**: Resume waiting on submitted jobs or follow their progress with
+//!   [`wait_for_proof`](client::SindriClient::wait_for_proof) and
+//!   [`watch_proof`](client::SindriClient::watch_proof)
+//!
+//! - **Administration**: Rename, publish, label and delete projects with
+//!   [`update_project_settings`](client::SindriClient::update_project_settings) and
+//!   [`delete_project`](client::SindriClient::delete_project), and manage team membership with
+//!   [`invite_team_member`](client::SindriClient::invite_team_member) and API keys with
+//!   [`generate_api_key`](client::SindriClient::generate_api_key)
+//!
 //! - **Collaboration**: Share and reuse public circuits using
 //!   [`clone_circuit`](client::SindriClient::clone_circuit)
 //!
@@ -59,11 +73,27 @@
 //! For more detailed documentation, refer to the [`client::SindriClient`] struct.
 //!
 
+pub(crate) mod api_keys;
+pub mod blocking;
+pub(crate) mod circuits;
 pub mod client;
 
 pub(crate) mod custom_middleware;
+mod error;
+pub use error::{Error, ManifestViolation, StatusCode};
+pub use tokio_util::sync::CancellationToken;
+pub(crate) mod job_batch;
 pub(crate) mod job_create;
+pub(crate) mod job_wait;
+pub(crate) mod job_watch;
 pub(crate) mod jwt;
+pub mod manifest;
+pub(crate) mod projects;
+pub(crate) mod proofs;
+pub(crate) mod smart_contracts;
+pub(crate) mod teams;
+pub(crate) mod upload;
+pub(crate) mod usage;
 pub(crate) mod utils;
 
 pub mod integrations;
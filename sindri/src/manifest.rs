@@ -0,0 +1,520 @@
+//! Sindri manifests (`sindri.json`), which describe the framework and build settings of a
+//! circuit project.
+//!
+//! Manifests can be written programmatically with the typed [`SindriManifest`] model, and are
+//! validated against the manifest JSON Schema so that mistakes are caught before a project is
+//! uploaded rather than after a failed compilation.
+
+use std::path::Path;
+
+use jsonschema::error::ValidationErrorKind;
+use serde::{Deserialize, Serialize};
+use sindri_openapi::apis::sindri_manifest_schema;
+use tracing::{debug, warn};
+
+use crate::{
+    client::SindriClient,
+    types::CircuitType,
+    utils::{read_manifest, SINDRI_MANIFEST_FILENAME},
+    Error, ManifestViolation,
+};
+
+/// Fields of a manifest which are not modeled by the SDK, e.g. `$schema`.
+///
+/// These are preserved when a manifest is read and written back.
+pub type ExtraFields = serde_json::Map<String, serde_json::Value>;
+
+/// The contents of a Sindri manifest, with one variant per framework.
+///
+/// The variant is selected by the `circuitType` field of the manifest.
+///
+/// # Examples
+///
+/// ```
+/// use sindri::{
+///     manifest::{CircomManifest, SindriManifest},
+///     CircuitType,
+/// };
+///
+/// let manifest = SindriManifest::from(CircomManifest {
+///     name: "multiplier".to_string(),
+///     curve: Some("bn254".to_string()),
+///     proving_scheme: Some("groth16".to_string()),
+///     ..Default::default()
+/// });
+/// assert_eq!(manifest.circuit_type(), CircuitType::Circom);
+///
+/// let json = serde_json::to_value(&manifest).unwrap();
+/// assert_eq!(json["circuitType"], "circom");
+/// assert_eq!(json["provingScheme"], "groth16");
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "circuitType", rename_all = "lowercase")]
+pub enum SindriManifest {
+    Boojum(BoojumManifest),
+    Circom(CircomManifest),
+    Gnark(GnarkManifest),
+    Halo2(Halo2Manifest),
+    Hermez(HermezManifest),
+    Jolt(JoltManifest),
+    Noir(NoirManifest),
+    Openvm(OpenvmManifest),
+    Plonky2(Plonky2Manifest),
+    Snarkvm(SnarkvmManifest),
+    Sp1(Sp1Manifest),
+}
+
+/// Defines the manifest of a framework, with the `name` field common to all frameworks and a
+/// catch-all for fields which are not modeled.
+macro_rules! framework_manifest {
+    (
+        $(#[$meta:meta])*
+        $manifest:ident => $variant:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field:ident: $ty:ty,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct $manifest {
+            /// Name of the project
+            pub name: String,
+            $(
+                $(#[$field_meta])*
+                #[serde(default, skip_serializing_if = "Option::is_none")]
+                pub $field: Option<$ty>,
+            )*
+            /// Fields which are not modeled by the SDK
+            #[serde(flatten)]
+            pub extra: ExtraFields,
+        }
+
+        impl From<$manifest> for SindriManifest {
+            fn from(manifest: $manifest) -> Self {
+                SindriManifest::$variant(manifest)
+            }
+        }
+    };
+}
+
+framework_manifest! {
+    /// Manifest of a Boojum project.
+    BoojumManifest => Boojum {}
+}
+
+framework_manifest! {
+    /// Manifest of a Circom project.
+    CircomManifest => Circom {
+        /// Path to the main circuit file, relative to the project root
+        circuit_path: String,
+        /// Curve of the proving system, e.g. `bn254`
+        curve: String,
+        /// Proving scheme, e.g. `groth16`
+        proving_scheme: String,
+        /// Witness generator, either `c++` or `wasm`
+        witness_compiler: String,
+    }
+}
+
+framework_manifest! {
+    /// Manifest of a Gnark project.
+    GnarkManifest => Gnark {
+        /// Name of the Go struct defining the circuit
+        circuit_struct_name: String,
+        /// Curve of the proving system, e.g. `bn254`
+        curve: String,
+        /// Gnark release, e.g. `v0.9.0`
+        gnark_version: String,
+        /// Name of the Go package containing the circuit
+        package_name: String,
+        /// Proving scheme, either `groth16` or `plonk`
+        proving_scheme: String,
+    }
+}
+
+framework_manifest! {
+    /// Manifest of a Halo2 project.
+    Halo2Manifest => Halo2 {
+        /// Path of the Rust type implementing the circuit
+        class_name: String,
+        /// Base-2 logarithm of the number of rows of the circuit
+        degree: u32,
+        /// Halo2 fork and release, e.g. `axiom-v0.3.0`
+        halo2_version: String,
+        /// Name of the Rust package containing the circuit
+        package_name: String,
+        /// Thread builder of Axiom circuits
+        thread_builder: String,
+    }
+}
+
+framework_manifest! {
+    /// Manifest of a Hermez project.
+    HermezManifest => Hermez {}
+}
+
+framework_manifest! {
+    /// Manifest of a Jolt project.
+    JoltManifest => Jolt {
+        /// Name of the guest function to prove
+        guest_function: String,
+        /// Jolt release
+        jolt_version: String,
+        /// Name of the Rust package containing the guest code
+        package_name: String,
+        /// Proving scheme, e.g. `hyperkzg`
+        proving_scheme: String,
+        /// Whether the guest code uses the standard library
+        std_enabled: bool,
+    }
+}
+
+framework_manifest! {
+    /// Manifest of a Noir project.
+    NoirManifest => Noir {
+        /// Noir release, e.g. `0.23.0`
+        noir_version: String,
+        /// Proving scheme, e.g. `barretenberg`
+        proving_scheme: String,
+    }
+}
+
+framework_manifest! {
+    /// Manifest of an OpenVM project.
+    OpenvmManifest => Openvm {}
+}
+
+framework_manifest! {
+    /// Manifest of a Plonky2 project.
+    Plonky2Manifest => Plonky2 {
+        /// Name of the Rust package containing the circuit
+        package_name: String,
+        /// Plonky2 release
+        plonky2_version: String,
+        /// Proving scheme
+        proving_scheme: String,
+        /// Name of the Rust struct defining the circuit
+        struct_name: String,
+    }
+}
+
+framework_manifest! {
+    /// Manifest of a SnarkVM project.
+    SnarkvmManifest => Snarkvm {}
+}
+
+framework_manifest! {
+    /// Manifest of an SP1 project.
+    Sp1Manifest => Sp1 {
+        /// Path to the compiled guest program, relative to the project root
+        elf_path: String,
+        /// Proving scheme, e.g. `compressed` or `groth16`
+        proving_scheme: String,
+        /// SP1 release, e.g. `5.0.0`
+        sp1_version: String,
+    }
+}
+
+impl SindriManifest {
+    /// Returns the name of the project.
+    pub fn name(&self) -> &str {
+        match self {
+            SindriManifest::Boojum(manifest) => &manifest.name,
+            SindriManifest::Circom(manifest) => &manifest.name,
+            SindriManifest::Gnark(manifest) => &manifest.name,
+            SindriManifest::Halo2(manifest) => &manifest.name,
+            SindriManifest::Hermez(manifest) => &manifest.name,
+            SindriManifest::Jolt(manifest) => &manifest.name,
+            SindriManifest::Noir(manifest) => &manifest.name,
+            SindriManifest::Openvm(manifest) => &manifest.name,
+            SindriManifest::Plonky2(manifest) => &manifest.name,
+            SindriManifest::Snarkvm(manifest) => &manifest.name,
+            SindriManifest::Sp1(manifest) => &manifest.name,
+        }
+    }
+
+    /// Returns the framework the project targets.
+    pub fn circuit_type(&self) -> CircuitType {
+        match self {
+            SindriManifest::Boojum(_) => CircuitType::Boojum,
+            SindriManifest::Circom(_) => CircuitType::Circom,
+            SindriManifest::Gnark(_) => CircuitType::Gnark,
+            SindriManifest::Halo2(_) => CircuitType::Halo2,
+            SindriManifest::Hermez(_) => CircuitType::Hermez,
+            SindriManifest::Jolt(_) => CircuitType::Jolt,
+            SindriManifest::Noir(_) => CircuitType::Noir,
+            SindriManifest::Openvm(_) => CircuitType::Openvm,
+            SindriManifest::Plonky2(_) => CircuitType::Plonky2,
+            SindriManifest::Snarkvm(_) => CircuitType::Snarkvm,
+            SindriManifest::Sp1(_) => CircuitType::Sp1,
+        }
+    }
+
+    /// Reads the manifest in the root of a project directory.
+    pub fn from_dir(dir: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::from_json(read_manifest(dir.as_ref())?)
+    }
+
+    /// Writes the manifest to the root of a project directory, replacing any existing manifest.
+    ///
+    /// Returns the path of the written file.
+    pub fn write_to_dir(&self, dir: impl AsRef<Path>) -> Result<std::path::PathBuf, Error> {
+        let path = dir.as_ref().join(SINDRI_MANIFEST_FILENAME);
+        std::fs::write(&path, serde_json::to_string_pretty(self)?)?;
+        Ok(path)
+    }
+
+    /// Converts a parsed manifest into its typed model.
+    pub(crate) fn from_json(manifest: serde_json::Value) -> Result<Self, Error> {
+        serde_json::from_value(manifest)
+            .map_err(|e| Error::Packaging(format!("Invalid {}: {}", SINDRI_MANIFEST_FILENAME, e)))
+    }
+}
+
+/// Minimal manifest schema used when the schema cannot be fetched from Sindri.
+///
+/// It only requires a non-empty `name` and `circuitType`, so it accepts many manifests
+/// which the schema served by Sindri rejects.
+const BUNDLED_MANIFEST_SCHEMA: &str = include_str!("../schemas/sindri-manifest-schema.json");
+
+/// Returns the manifest schema bundled with the SDK.
+pub(crate) fn bundled_manifest_schema() -> serde_json::Value {
+    serde_json::from_str(BUNDLED_MANIFEST_SCHEMA).expect("bundled manifest schema is valid JSON")
+}
+
+/// Checks a parsed manifest against a manifest schema, collecting every violation.
+pub(crate) fn validate_manifest_against(
+    manifest: &serde_json::Value,
+    schema: &serde_json::Value,
+) -> Result<(), Error> {
+    let validator = jsonschema::validator_for(schema).map_err(|e| {
+        Error::Packaging(format!(
+            "Invalid {} schema: {}",
+            SINDRI_MANIFEST_FILENAME, e
+        ))
+    })?;
+    let mut violations: Vec<_> = validator
+        .iter_errors(manifest)
+        .map(|e| {
+            let mut path = e.instance_path.to_string();
+            // Point missing fields at where they belong, rather than at their parent
+            if let ValidationErrorKind::Required { property } = &e.kind {
+                if let Some(property) = property.as_str() {
+                    path = format!("{}/{}", path, property);
+                }
+            }
+            ManifestViolation {
+                path,
+                message: e.to_string(),
+            }
+        })
+        .collect();
+    violations.sort_by(|a, b| a.path.cmp(&b.path));
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::InvalidManifest { violations })
+    }
+}
+
+impl SindriClient {
+    /// Retrieves the JSON Schema which Sindri manifests (`sindri.json`) must satisfy.
+    ///
+    /// If the schema cannot be fetched or compiled, e.g. while offline, the copy bundled
+    /// with the SDK is returned instead.
+    pub async fn get_manifest_schema(&self) -> serde_json::Value {
+        match sindri_manifest_schema(&self.config).await {
+            Ok(schema) if jsonschema::validator_for(&schema).is_ok() => schema,
+            Ok(_) => {
+                warn!("Sindri returned an invalid manifest schema, using the bundled schema");
+                bundled_manifest_schema()
+            }
+            Err(e) => {
+                debug!("Could not fetch the manifest schema: {}", e);
+                warn!("Could not fetch the manifest schema, using the bundled schema");
+                bundled_manifest_schema()
+            }
+        }
+    }
+
+    /// Validates the Sindri manifest of a local project without uploading it.
+    ///
+    /// Projects are validated automatically by [`request_build`](Self::request_build),
+    /// so this is mainly useful to check a project ahead of time, e.g. in CI.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - Path to a local project directory containing a `sindri.json`
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Error::InvalidManifest`] listing the path of every offending field if the
+    /// manifest does not match the schema.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use sindri::{client::SindriClient, Error};
+    ///
+    /// let client = SindriClient::default();
+    /// if let Err(Error::InvalidManifest { violations }) =
+    ///     client.validate_manifest("path/to/project").await
+    /// {
+    ///     for violation in violations {
+    ///         eprintln!("{}: {}", violation.path, violation.message);
+    ///     }
+    /// }
+    /// # });
+    /// ```
+    pub async fn validate_manifest(&self, project: impl AsRef<Path>) -> Result<(), Error> {
+        let manifest = read_manifest(project.as_ref())?;
+        let schema = self.get_manifest_schema().await;
+        validate_manifest_against(&manifest, &schema)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    fn violation_paths(manifest: serde_json::Value) -> Vec<String> {
+        match validate_manifest_against(&manifest, &bundled_manifest_schema()) {
+            Ok(()) => vec![],
+            Err(Error::InvalidManifest { violations }) => {
+                violations.into_iter().map(|v| v.path).collect()
+            }
+            Err(e) => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_bundled_schema_accepts_examples() {
+        for manifest in [
+            serde_json::json!({
+                "$schema": "https://sindri.app/api/v1/sindri-manifest-schema.json",
+                "name": "web3-demo",
+                "circuitType": "circom",
+                "circuitPath": "circuit/circuit.circom",
+                "curve": "bn254",
+                "provingScheme": "groth16",
+                "witnessCompiler": "wasm"
+            }),
+            serde_json::json!({
+                "name": "zkvm-demo",
+                "circuitType": "sp1",
+                "provingScheme": "compressed",
+                "sp1Version": "5.0.0",
+                "elfPath": "program/regex-program"
+            }),
+            serde_json::json!({"name": "test-circuit", "circuitType": "circom"}),
+        ] {
+            assert_eq!(violation_paths(manifest), Vec::<String>::new());
+        }
+    }
+
+    #[test]
+    fn test_bundled_schema_reports_field_paths() {
+        let paths = violation_paths(serde_json::json!({"name": "", "circuitType": 7}));
+        assert_eq!(paths, vec!["/circuitType", "/name"]);
+
+        // Missing fields are reported at their own path
+        let paths = violation_paths(serde_json::json!({"circuitType": "sp1"}));
+        assert_eq!(paths, vec!["/name"]);
+    }
+
+    #[test]
+    fn test_manifest_roundtrip() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            temp_dir.path().join(SINDRI_MANIFEST_FILENAME),
+            r#"{
+                "$schema": "https://sindri.app/api/v1/sindri-manifest-schema.json",
+                "name": "zkvm-demo",
+                "circuitType": "sp1",
+                "provingScheme": "compressed",
+                "sp1Version": "5.0.0",
+                "elfPath": "program/regex-program"
+            }"#,
+        )
+        .unwrap();
+
+        let manifest = SindriManifest::from_dir(temp_dir.path()).unwrap();
+        assert_eq!(manifest.name(), "zkvm-demo");
+        assert_eq!(manifest.circuit_type(), CircuitType::Sp1);
+        let SindriManifest::Sp1(ref sp1) = manifest else {
+            panic!("Expected an SP1 manifest");
+        };
+        assert_eq!(sp1.elf_path.as_deref(), Some("program/regex-program"));
+        assert!(sp1.extra.contains_key("$schema"));
+
+        // Unmodeled fields survive a roundtrip
+        manifest.write_to_dir(temp_dir.path()).unwrap();
+        assert_eq!(SindriManifest::from_dir(temp_dir.path()).unwrap(), manifest);
+    }
+
+    #[test]
+    fn test_manifest_unknown_circuit_type() {
+        let error = SindriManifest::from_json(serde_json::json!({
+            "name": "demo",
+            "circuitType": "cairo",
+        }))
+        .unwrap_err();
+        assert!(matches!(error, Error::Packaging(_)));
+        assert!(error.to_string().contains("unknown variant `cairo`"));
+    }
+
+    #[tokio::test]
+    async fn test_validate_manifest_uses_server_schema() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/sindri-manifest-schema.json"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "type": "object",
+                "required": ["name", "circuitType", "provingScheme"],
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            temp_dir.path().join(SINDRI_MANIFEST_FILENAME),
+            r#"{"name": "demo", "circuitType": "noir"}"#,
+        )
+        .unwrap();
+
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+        let error = client.validate_manifest(temp_dir.path()).await.unwrap_err();
+        match error {
+            Error::InvalidManifest { violations } => {
+                assert_eq!(violations.len(), 1);
+                assert!(violations[0].message.contains("provingScheme"));
+            }
+            e => panic!("Unexpected error: {}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_manifest_schema_falls_back_to_bundled() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/sindri-manifest-schema.json"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+        assert_eq!(
+            client.get_manifest_schema().await,
+            bundled_manifest_schema()
+        );
+    }
+}
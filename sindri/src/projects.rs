@@ -0,0 +1,202 @@
+//! These methods manage projects, which group the circuits built under the same name.
+
+use sindri_openapi::{
+    apis::{project_circuits, project_delete, project_detail, project_list, project_settings},
+    models::{CircuitInfoResponse, ProjectInfoResponse, ProjectListInput, ProjectSettingsInput},
+};
+use tracing::info;
+
+use crate::{client::SindriClient, Error};
+
+/// Changes to apply with [`SindriClient::update_project_settings`].
+///
+/// Settings which are left as `None` are not changed.
+///
+/// # Fields
+///
+/// * `name` - New name of the project
+/// * `is_public` - Whether the project and its circuits are visible to other teams
+/// * `labels` - Labels of the project, replacing the existing labels
+///
+/// # Examples
+///
+/// ```
+/// use sindri::client::ProjectSettings;
+///
+/// let settings = ProjectSettings {
+///     is_public: Some(true),
+///     labels: Some(vec!["release".to_string()]),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ProjectSettings {
+    pub name: Option<String>,
+    pub is_public: Option<bool>,
+    pub labels: Option<Vec<String>>,
+}
+
+impl From<ProjectSettings> for ProjectSettingsInput {
+    fn from(settings: ProjectSettings) -> Self {
+        ProjectSettingsInput {
+            is_public: settings.is_public.map(Some),
+            name: settings.name.map(Some),
+            labels: settings.labels.map(Some),
+        }
+    }
+}
+
+impl SindriClient {
+    /// Lists the projects of the authenticated team.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use sindri::client::SindriClient;
+    ///
+    /// let client = SindriClient::default();
+    /// for project in client.list_projects().await.unwrap() {
+    ///     println!("{} ({} proofs)", project.name, project.num_proofs.unwrap_or(0));
+    /// }
+    /// # });
+    /// ```
+    pub async fn list_projects(&self) -> Result<Vec<ProjectInfoResponse>, Error> {
+        Ok(project_list(&self.config, ProjectListInput::default()).await?)
+    }
+
+    /// Retrieves the details of a project.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - ID of the project to retrieve
+    pub async fn get_project(&self, project_id: &str) -> Result<ProjectInfoResponse, Error> {
+        Ok(project_detail(&self.config, project_id).await?)
+    }
+
+    /// Updates the settings of a project, e.g. to rename it, change its visibility
+    /// or replace its labels.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_name` - Name of the project to update
+    /// * `settings` - Changes to apply to the project
+    ///
+    /// # Returns
+    ///
+    /// Returns the project details after the update.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use sindri::client::{ProjectSettings, SindriClient};
+    ///
+    /// let client = SindriClient::default();
+    /// let settings = ProjectSettings {
+    ///     name: Some("my-project-v2".to_string()),
+    ///     is_public: Some(false),
+    ///     ..Default::default()
+    /// };
+    /// let project = client
+    ///     .update_project_settings("my-project", settings)
+    ///     .await
+    ///     .unwrap();
+    /// assert_eq!(project.name, "my-project-v2");
+    /// # });
+    /// ```
+    pub async fn update_project_settings(
+        &self,
+        project_name: &str,
+        settings: ProjectSettings,
+    ) -> Result<ProjectInfoResponse, Error> {
+        info!("Updating settings of project {}", project_name);
+        Ok(project_settings(&self.config, project_name, settings.into()).await?)
+    }
+
+    /// Lists the circuits built under a project.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - ID of the project whose circuits are listed, e.g. the `project_id` of a
+    ///   project returned by [`list_projects`](Self::list_projects)
+    pub async fn list_project_circuits(
+        &self,
+        project_id: &str,
+    ) -> Result<Vec<CircuitInfoResponse>, Error> {
+        Ok(project_circuits(&self.config, project_id).await?)
+    }
+
+    /// Deletes a project by ID, along with all of its circuits.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - ID of the project to delete
+    ///
+    /// # Warning
+    ///
+    /// Once deleted, none of the project's circuits can be used to generate proofs.
+    /// Prefer [`delete_circuit`](Self::delete_circuit) to remove individual builds.
+    pub async fn delete_project(&self, project_id: &str) -> Result<(), Error> {
+        info!("Deleting project with ID: {}", project_id);
+        project_delete(&self.config, project_id).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::{
+        matchers::{body_json, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[tokio::test]
+    async fn test_update_project_settings() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/project/demo/settings"))
+            .and(body_json(serde_json::json!({
+                "name": "demo-v2",
+                "labels": ["release"],
+            })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(ProjectInfoResponse {
+                    name: "demo-v2".to_string(),
+                    labels: vec!["release".to_string()],
+                    ..Default::default()
+                }),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+        let settings = ProjectSettings {
+            name: Some("demo-v2".to_string()),
+            labels: Some(vec!["release".to_string()]),
+            ..Default::default()
+        };
+        let project = client
+            .update_project_settings("demo", settings)
+            .await
+            .unwrap();
+        assert_eq!(project.name, "demo-v2");
+        assert_eq!(project.labels, vec!["release"]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_project_not_found() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/api/v1/project/missing/delete"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+        let error = client.delete_project("missing").await.unwrap_err();
+        assert_eq!(error.status(), Some(crate::StatusCode::NOT_FOUND));
+    }
+}
@@ -0,0 +1,293 @@
+//! These methods list proofs page by page as async streams, so that long proof
+//! histories can be processed without loading them into memory all at once.
+
+use std::future::Future;
+
+use futures::{stream, Stream, TryStreamExt};
+use sindri_openapi::{
+    apis::{circuit_proofs_paginated, project_proofs_paginated, proof_list_paginated},
+    models::{PagedProofInfoResponse, ProofInfoResponse},
+};
+use tracing::debug;
+
+use crate::{
+    client::SindriClient,
+    types::{InternalProofListInput, ProofListInput},
+    Error,
+};
+
+/// Number of proofs requested per page.
+const PAGE_SIZE: i32 = 100;
+
+/// Streams items from a paginated endpoint, requesting each page once the previous
+/// page has been consumed.
+///
+/// `fetch` is called with the limit and offset of the page to request. The stream ends
+/// after the last page, or after yielding the first error.
+fn paginate<'a, F, Fut>(fetch: F) -> impl Stream<Item = Result<ProofInfoResponse, Error>> + 'a
+where
+    F: Fn(i32, i32) -> Fut + 'a,
+    Fut: Future<Output = Result<PagedProofInfoResponse, Error>> + 'a,
+{
+    stream::try_unfold(Some(0), move |offset| {
+        let page = offset.map(|offset| (offset, fetch(PAGE_SIZE, offset)));
+        async move {
+            let Some((offset, page)) = page else {
+                return Ok(None);
+            };
+            page.await.map(|page| {
+                let next_offset = offset + page.items.len() as i32;
+                debug!(
+                    "Fetched proofs {} to {} of {}",
+                    offset, next_offset, page.count
+                );
+                let next = (!page.items.is_empty() && i64::from(next_offset) < page.count)
+                    .then_some(next_offset);
+                Some((stream::iter(page.items.into_iter().map(Ok)), next))
+            })
+        }
+    })
+    .try_flatten()
+}
+
+impl SindriClient {
+    /// Lists the proofs of the authenticated team which satisfy the given criteria.
+    ///
+    /// Proofs are fetched lazily, one page at a time, as the stream is consumed.
+    /// Large payloads like proofs and verification keys are not included.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Criteria the returned proofs must satisfy
+    ///
+    /// # Returns
+    ///
+    /// Returns a stream of proofs which ends after the last page, or after yielding an error.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use futures::TryStreamExt;
+    /// use sindri::{client::SindriClient, JobStatus, ProofListInput};
+    ///
+    /// let client = SindriClient::default();
+    /// let input = ProofListInput {
+    ///     project_name: Some("my-project".to_string()),
+    ///     status: Some(JobStatus::Ready),
+    ///     ..Default::default()
+    /// };
+    /// let mut proofs = std::pin::pin!(client.proofs(input));
+    /// while let Some(proof) = proofs.try_next().await.unwrap() {
+    ///     println!("{} {:?}", proof.proof_id, proof.compute_time_sec);
+    /// }
+    /// # });
+    /// ```
+    pub fn proofs(
+        &self,
+        input: ProofListInput,
+    ) -> impl Stream<Item = Result<ProofInfoResponse, Error>> + '_ {
+        let input = InternalProofListInput::from(input);
+        paginate(move |limit, offset| {
+            let input = input.clone();
+            async move {
+                proof_list_paginated(&self.config, input, Some(limit), Some(offset))
+                    .await
+                    .map_err(Error::from)
+            }
+        })
+    }
+
+    /// Lists the proofs of a circuit.
+    ///
+    /// Proofs are fetched lazily, one page at a time, as the stream is consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `circuit_id` - ID of the circuit whose proofs are listed
+    ///
+    /// # Returns
+    ///
+    /// Returns a stream of proofs which ends after the last page, or after yielding an error.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use futures::TryStreamExt;
+    /// use sindri::client::SindriClient;
+    ///
+    /// let client = SindriClient::default();
+    /// let proofs: Vec<_> = client
+    ///     .circuit_proofs("team_name/project_name:tag")
+    ///     .try_collect()
+    ///     .await
+    ///     .unwrap();
+    /// println!("Circuit has {} proofs", proofs.len());
+    /// # });
+    /// ```
+    pub fn circuit_proofs(
+        &self,
+        circuit_id: &str,
+    ) -> impl Stream<Item = Result<ProofInfoResponse, Error>> + '_ {
+        let circuit_id = circuit_id.to_string();
+        paginate(move |limit, offset| {
+            let circuit_id = circuit_id.clone();
+            async move {
+                circuit_proofs_paginated(&self.config, &circuit_id, Some(limit), Some(offset))
+                    .await
+                    .map_err(Error::from)
+            }
+        })
+    }
+
+    /// Lists the proofs of every circuit in a project.
+    ///
+    /// Proofs are fetched lazily, one page at a time, as the stream is consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - ID of the project whose proofs are listed
+    ///
+    /// # Returns
+    ///
+    /// Returns a stream of proofs which ends after the last page, or after yielding an error.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use futures::StreamExt;
+    /// use sindri::client::SindriClient;
+    ///
+    /// let client = SindriClient::default();
+    /// let failed = client
+    ///     .project_proofs("project-uuid")
+    ///     .filter(|proof| std::future::ready(proof.as_ref().is_ok_and(|p| p.error.is_some())))
+    ///     .count()
+    ///     .await;
+    /// println!("{} proofs failed", failed);
+    /// # });
+    /// ```
+    pub fn project_proofs(
+        &self,
+        project_id: &str,
+    ) -> impl Stream<Item = Result<ProofInfoResponse, Error>> + '_ {
+        let project_id = project_id.to_string();
+        paginate(move |limit, offset| {
+            let project_id = project_id.clone();
+            async move {
+                project_proofs_paginated(&self.config, &project_id, Some(limit), Some(offset))
+                    .await
+                    .map_err(Error::from)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use wiremock::{
+        matchers::{body_json, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    fn page(ids: std::ops::Range<usize>, count: i64) -> PagedProofInfoResponse {
+        PagedProofInfoResponse {
+            items: ids
+                .map(|i| ProofInfoResponse {
+                    proof_id: format!("proof_{}", i),
+                    ..Default::default()
+                })
+                .collect(),
+            count,
+        }
+    }
+
+    async fn mount_page(mock_server: &MockServer, offset: usize, response: ResponseTemplate) {
+        Mock::given(method("GET"))
+            .and(path("/api/v1/circuit/test_circuit_123/proofs/paginated"))
+            .and(query_param("limit", PAGE_SIZE.to_string()))
+            .and(query_param("offset", offset.to_string()))
+            .respond_with(response)
+            .expect(1)
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_circuit_proofs_fetches_pages_lazily() {
+        let mock_server = MockServer::start().await;
+        mount_page(
+            &mock_server,
+            0,
+            ResponseTemplate::new(200).set_body_json(page(0..100, 150)),
+        )
+        .await;
+        mount_page(
+            &mock_server,
+            100,
+            ResponseTemplate::new(200).set_body_json(page(100..150, 150)),
+        )
+        .await;
+
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+        let mut proofs = std::pin::pin!(client.circuit_proofs("test_circuit_123"));
+
+        // Only the first page is requested until its proofs are consumed
+        let first = proofs.next().await.unwrap().unwrap();
+        assert_eq!(first.proof_id, "proof_0");
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+
+        let rest: Vec<_> = proofs.collect().await;
+        assert_eq!(rest.len(), 149);
+        assert_eq!(rest.last().unwrap().as_ref().unwrap().proof_id, "proof_149");
+    }
+
+    #[tokio::test]
+    async fn test_circuit_proofs_ends_after_error() {
+        let mock_server = MockServer::start().await;
+        mount_page(
+            &mock_server,
+            0,
+            ResponseTemplate::new(200).set_body_json(page(0..100, 300)),
+        )
+        .await;
+        mount_page(&mock_server, 100, ResponseTemplate::new(404)).await;
+
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+        let proofs: Vec<_> = client.circuit_proofs("test_circuit_123").collect().await;
+
+        assert_eq!(proofs.len(), 101);
+        assert!(proofs[..100].iter().all(Result::is_ok));
+        assert_eq!(
+            proofs[100].as_ref().unwrap_err().status(),
+            Some(crate::StatusCode::NOT_FOUND)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_proofs_sends_filters() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/proof/list/paginated"))
+            .and(body_json(serde_json::json!({
+                "project_name": "demo",
+                "status": "Ready",
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page(0..2, 2)))
+            .mount(&mock_server)
+            .await;
+
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+        let input = ProofListInput {
+            project_name: Some("demo".to_string()),
+            status: Some(crate::JobStatus::Ready),
+            ..Default::default()
+        };
+        let proofs: Vec<_> = client.proofs(input).try_collect().await.unwrap();
+        assert_eq!(proofs.len(), 2);
+    }
+}
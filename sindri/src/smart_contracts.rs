@@ -0,0 +1,153 @@
+//! These methods retrieve the artifacts needed to verify proofs on-chain.
+
+use sindri_openapi::apis::circuit_smart_contract_verifier;
+use tracing::info;
+
+use crate::{client::SindriClient, types::SmartContractVerifier, Error, StatusCode};
+
+impl SindriClient {
+    /// Retrieves the Solidity smart contract which verifies proofs of a circuit on-chain.
+    ///
+    /// Only circuits whose proving scheme supports on-chain verification have a verifier,
+    /// as indicated by [`has_smart_contract_verifier`](crate::CircuitInfo::has_smart_contract_verifier).
+    ///
+    /// # Arguments
+    ///
+    /// * `circuit_id` - ID of the circuit whose verifier is retrieved
+    ///
+    /// # Returns
+    ///
+    /// Returns the contract source, or [`Error::NoSmartContractVerifier`] if the circuit
+    /// has no verifier.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use sindri::client::SindriClient;
+    ///
+    /// let client = SindriClient::default();
+    /// let verifier = client
+    ///     .get_smart_contract_verifier("team_name/project_name:tag")
+    ///     .await
+    ///     .unwrap();
+    /// let path = verifier.write_to_file("contracts/Verifier").unwrap();
+    /// println!("Wrote verifier to {}", path.display());
+    /// # });
+    /// ```
+    pub async fn get_smart_contract_verifier(
+        &self,
+        circuit_id: &str,
+    ) -> Result<SmartContractVerifier, Error> {
+        info!(
+            "Retrieving smart contract verifier for circuit {}",
+            circuit_id
+        );
+        let no_verifier = || Error::NoSmartContractVerifier {
+            id: circuit_id.to_string(),
+        };
+        let response = match circuit_smart_contract_verifier(&self.config, circuit_id)
+            .await
+            .map_err(Error::from)
+        {
+            Ok(response) => response,
+            // Unsupported proving schemes are reported as not implemented
+            Err(e) if e.status() == Some(StatusCode::NOT_IMPLEMENTED) => return Err(no_verifier()),
+            Err(e) => return Err(e),
+        };
+        let contract_code = response
+            .contract_code
+            .filter(|code| !code.is_empty())
+            .ok_or_else(no_verifier)?;
+        Ok(SmartContractVerifier {
+            circuit_id: circuit_id.to_string(),
+            contract_code,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sindri_openapi::models::SmartContractVerifierResponse;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    async fn mount_verifier(
+        mock_server: &MockServer,
+        circuit_id: &str,
+        response: ResponseTemplate,
+    ) {
+        Mock::given(method("GET"))
+            .and(path(format!(
+                "/api/v1/circuit/{}/smart_contract_verifier",
+                circuit_id
+            )))
+            .respond_with(response)
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_get_smart_contract_verifier() {
+        let mock_server = MockServer::start().await;
+        mount_verifier(
+            &mock_server,
+            "groth16_circuit",
+            ResponseTemplate::new(200).set_body_json(SmartContractVerifierResponse {
+                contract_code: Some("contract Verifier {}".to_string()),
+            }),
+        )
+        .await;
+
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+        let verifier = client
+            .get_smart_contract_verifier("groth16_circuit")
+            .await
+            .unwrap();
+        assert_eq!(verifier.contract_code, "contract Verifier {}");
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let written = verifier
+            .write_to_file(temp_dir.path().join("Verifier"))
+            .unwrap();
+        assert_eq!(written, temp_dir.path().join("Verifier.sol"));
+        assert_eq!(
+            std::fs::read_to_string(written).unwrap(),
+            "contract Verifier {}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_smart_contract_verifier_unsupported() {
+        let mock_server = MockServer::start().await;
+        mount_verifier(
+            &mock_server,
+            "plonky2_circuit",
+            ResponseTemplate::new(501).set_body_json(serde_json::json!({
+                "error": "ComingSoon",
+                "message": "Smart contract verifiers are not supported for this circuit"
+            })),
+        )
+        .await;
+        mount_verifier(
+            &mock_server,
+            "empty_circuit",
+            ResponseTemplate::new(200).set_body_json(SmartContractVerifierResponse {
+                contract_code: None,
+            }),
+        )
+        .await;
+
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+        for circuit_id in ["plonky2_circuit", "empty_circuit"] {
+            let error = client
+                .get_smart_contract_verifier(circuit_id)
+                .await
+                .unwrap_err();
+            assert!(matches!(error, Error::NoSmartContractVerifier { id } if id == circuit_id));
+        }
+    }
+}
@@ -0,0 +1,320 @@
+//! These methods administer teams and their members, e.g. to onboard or offboard
+//! engineers from scripts.
+
+use std::path::Path;
+
+use sindri_openapi::{
+    apis::{
+        team_avatar_upload, team_create, team_detail, team_invite, team_me, team_members,
+        team_remove_member, team_settings,
+    },
+    models::{
+        TeamCreateInput, TeamDetail, TeamInviteInput, TeamMemberDetail, TeamRemoveMemberInput,
+        TeamSettingsInput,
+    },
+};
+use tracing::info;
+
+use crate::{client::SindriClient, Error};
+
+/// Changes to apply with [`SindriClient::update_team_settings`].
+///
+/// Settings which are left as `None` are not changed.
+///
+/// # Fields
+///
+/// * `display_name` - Name of the team shown on the Sindri dashboard
+/// * `github_url` - Link to the team's GitHub profile
+/// * `twitter_url` - Link to the team's Twitter profile
+/// * `url` - Link to the team's website
+///
+/// # Examples
+///
+/// ```
+/// use sindri::client::TeamSettings;
+///
+/// let settings = TeamSettings {
+///     display_name: Some("Sindri Labs".to_string()),
+///     url: Some("https://sindri.app".to_string()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TeamSettings {
+    pub display_name: Option<String>,
+    pub github_url: Option<String>,
+    pub twitter_url: Option<String>,
+    pub url: Option<String>,
+}
+
+impl From<TeamSettings> for TeamSettingsInput {
+    fn from(settings: TeamSettings) -> Self {
+        TeamSettingsInput {
+            display_name: settings.display_name.map(Some),
+            github_url: settings.github_url.map(Some),
+            twitter_url: settings.twitter_url.map(Some),
+            url: settings.url.map(Some),
+        }
+    }
+}
+
+impl SindriClient {
+    /// Creates a new team, with the authenticated user as its first member.
+    ///
+    /// # Arguments
+    ///
+    /// * `slug` - Unique identifier of the team, used in circuit IDs like `slug/project:tag`
+    /// * `display_name` - Optional name of the team shown on the Sindri dashboard
+    /// * `description` - Optional description of the team
+    pub async fn create_team(
+        &self,
+        slug: &str,
+        display_name: Option<String>,
+        description: Option<String>,
+    ) -> Result<TeamDetail, Error> {
+        info!("Creating team {}", slug);
+        let input = TeamCreateInput {
+            slug: slug.to_string(),
+            display_name,
+            description,
+        };
+        Ok(team_create(&self.config, input, None).await?)
+    }
+
+    /// Retrieves the details of a team.
+    ///
+    /// # Arguments
+    ///
+    /// * `team_slug` - Slug of the team to retrieve
+    pub async fn get_team(&self, team_slug: &str) -> Result<TeamDetail, Error> {
+        Ok(team_detail(&self.config, team_slug).await?)
+    }
+
+    /// Retrieves the details of the authenticated team.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use sindri::client::SindriClient;
+    ///
+    /// let client = SindriClient::default();
+    /// let team = client.get_current_team().await.unwrap();
+    /// println!("Authenticated as team {} ({})", team.name, team.slug);
+    /// # });
+    /// ```
+    pub async fn get_current_team(&self) -> Result<TeamDetail, Error> {
+        let response = team_me(&self.config).await?;
+        Ok(*response.team)
+    }
+
+    /// Lists the members of a team.
+    ///
+    /// # Arguments
+    ///
+    /// * `team_slug` - Slug of the team whose members are listed
+    pub async fn list_team_members(&self, team_slug: &str) -> Result<Vec<TeamMemberDetail>, Error> {
+        let response = team_members(&self.config, team_slug, None).await?;
+        Ok(response.members)
+    }
+
+    /// Invites an email address to join the authenticated team.
+    ///
+    /// # Arguments
+    ///
+    /// * `email` - Email address to send the invitation to
+    pub async fn invite_team_member(&self, email: &str) -> Result<(), Error> {
+        info!("Inviting {} to the team", email);
+        let input = TeamInviteInput {
+            email: email.to_string(),
+        };
+        team_invite(&self.config, input, None).await?;
+        Ok(())
+    }
+
+    /// Removes a user from a team.
+    ///
+    /// # Arguments
+    ///
+    /// * `team_slug` - Slug of the team to remove the user from
+    /// * `username` - Username of the member to remove
+    ///
+    /// # Warning
+    ///
+    /// Removing the last member of a team revokes all of the team's API keys.
+    pub async fn remove_team_member(&self, team_slug: &str, username: &str) -> Result<(), Error> {
+        info!("Removing {} from team {}", username, team_slug);
+        let input = TeamRemoveMemberInput {
+            team_slug: team_slug.to_string(),
+            username: username.to_string(),
+        };
+        team_remove_member(&self.config, input, None).await?;
+        Ok(())
+    }
+
+    /// Updates the profile settings of the authenticated team.
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - Changes to apply to the team
+    ///
+    /// # Returns
+    ///
+    /// Returns the team details after the update.
+    pub async fn update_team_settings(&self, settings: TeamSettings) -> Result<TeamDetail, Error> {
+        Ok(team_settings(&self.config, settings.into()).await?)
+    }
+
+    /// Uploads an image file as the avatar of the authenticated team.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the image file to upload
+    ///
+    /// # Returns
+    ///
+    /// Returns the team details after the upload, including the new `avatar_url`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use sindri::client::SindriClient;
+    ///
+    /// let client = SindriClient::default();
+    /// let team = client.upload_team_avatar("assets/logo.png").await.unwrap();
+    /// println!("New avatar at {}", team.avatar_url);
+    /// # });
+    /// ```
+    pub async fn upload_team_avatar(&self, path: impl AsRef<Path>) -> Result<TeamDetail, Error> {
+        let path = path.as_ref();
+        let filename = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| {
+                Error::Validation(format!("Avatar path has no file name: {}", path.display()))
+            })?;
+        let image = tokio::fs::read(path).await?;
+        info!("Uploading team avatar {} ({} bytes)", filename, image.len());
+        let content_type = image_content_type(path);
+        let response = team_avatar_upload(&self.config, image, filename, content_type).await?;
+        Ok(*response.team)
+    }
+}
+
+/// Guesses the MIME type of an image from its file extension.
+fn image_content_type(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase);
+    match extension.as_deref() {
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("svg") => "image/svg+xml",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sindri_openapi::models::{TeamMeResponse, TeamMembersResponse};
+    use wiremock::{
+        matchers::{body_string_contains, header_regex, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    fn team_me_response() -> TeamMeResponse {
+        TeamMeResponse {
+            team: Box::new(TeamDetail {
+                slug: "test-team".to_string(),
+                avatar_url: "https://example.com/avatar.png".to_string(),
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_team_members() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/team/test-team/members"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(TeamMembersResponse {
+                    team_slug: "test-team".to_string(),
+                    members: vec![TeamMemberDetail {
+                        email: "dev@example.com".to_string(),
+                        username: "dev".to_string(),
+                    }],
+                }),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+        let members = client.list_team_members("test-team").await.unwrap();
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].username, "dev");
+    }
+
+    #[tokio::test]
+    async fn test_upload_team_avatar() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/team/avatar/upload"))
+            .and(header_regex(
+                "content-type",
+                "^multipart/form-data; boundary=",
+            ))
+            .and(body_string_contains("filename=\"logo.png\""))
+            .and(body_string_contains("Content-Type: image/png\r\n"))
+            .and(body_string_contains("not really a png"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(team_me_response()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let avatar_path = temp_dir.path().join("logo.png");
+        std::fs::write(&avatar_path, "not really a png").unwrap();
+
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+        let team = client.upload_team_avatar(&avatar_path).await.unwrap();
+        assert_eq!(team.avatar_url, "https://example.com/avatar.png");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_upload_team_avatar_escapes_filename() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/team/avatar/upload"))
+            .and(body_string_contains(
+                "filename=\"a%22b%0D%0AX-Injected: 1.JPG\"\r\nContent-Type: image/jpeg\r\n",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(team_me_response()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let avatar_path = temp_dir.path().join("a\"b\r\nX-Injected: 1.JPG");
+        std::fs::write(&avatar_path, "not really a jpeg").unwrap();
+
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+        client.upload_team_avatar(&avatar_path).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_upload_team_avatar_missing_file() {
+        let client = SindriClient::default();
+        let error = client
+            .upload_team_avatar("does/not/exist.png")
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::Io(_)));
+    }
+}
@@ -1,21 +1,31 @@
 //! Common types re-exported from the openapi (internal) package.
 use base64::engine::{general_purpose, Engine};
+use chrono::{DateTime, Utc};
 pub use sindri_openapi::models::{
-    BoojumCircuitInfoResponse, CircomCircuitInfoResponse, CircuitInfoResponse, CircuitType,
-    GnarkCircuitInfoResponse, Halo2CircuitInfoResponse, HermezCircuitInfoResponse, JobStatus,
-    JoltCircuitInfoResponse, NoirCircuitInfoResponse, Plonky2CircuitInfoResponse,
-    ProofInfoResponse, ProofInput as InternalProofInput, SnarkvmCircuitInfoResponse,
-    Sp1CircuitInfoResponse, TeamDetail,
+    ApiKeyResponse, BoojumCircuitInfoResponse, CircomCircuitInfoResponse, CircuitInfoResponse,
+    CircuitType, GnarkCircuitInfoResponse, Halo2CircuitInfoResponse, HermezCircuitInfoResponse,
+    JobStatus, JoltCircuitInfoResponse, NoirCircuitInfoResponse, Plonky2CircuitInfoResponse,
+    ProjectInfoResponse, ProofInfoResponse, ProofInput as InternalProofInput,
+    ProofListInput as InternalProofListInput, SnarkvmCircuitInfoResponse, Sp1CircuitInfoResponse,
+    TeamDetail,
 };
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use crate::Error;
 
 /// Helper trait to extract common fields from CircuitInfoResponse
 pub trait CircuitInfo {
+    fn circuit_type(&self) -> CircuitType;
     fn compute_time_sec(&self) -> Option<f64>;
     fn date_created(&self) -> &str;
     fn error(&self) -> Option<String>;
     fn file_size(&self) -> Option<i64>;
     fn finished_processing(&self) -> bool;
+    fn has_smart_contract_verifier(&self) -> bool;
     fn id(&self) -> &str;
     fn meta(&self) -> &HashMap<String, String>;
     fn num_proofs(&self) -> Option<i64>;
@@ -31,6 +41,14 @@ macro_rules! impl_circuit_info {
     ($($variant:ident),*) => {
         impl CircuitInfo for CircuitInfoResponse {
 
+            fn circuit_type(&self) -> CircuitType {
+                match self {
+                    $(
+                        CircuitInfoResponse::$variant(_) => CircuitType::$variant,
+                    )*
+                }
+            }
+
             fn compute_time_sec(&self) -> Option<f64> {
                 match self {
                     $(
@@ -71,6 +89,14 @@ macro_rules! impl_circuit_info {
                 }
             }
 
+            fn has_smart_contract_verifier(&self) -> bool {
+                match self {
+                    $(
+                        CircuitInfoResponse::$variant(response) => response.has_smart_contract_verifier,
+                    )*
+                }
+            }
+
             fn id(&self) -> &str {
                 match self {
                     $(
@@ -191,23 +217,275 @@ impl From<InternalProofInput> for ProofInput {
     }
 }
 
+/// Criteria for selecting proofs with [`proofs`](crate::client::SindriClient::proofs).
+///
+/// A typed counterpart of [`InternalProofListInput`]. Every field is optional, and a proof
+/// must satisfy all of the fields which are set.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use sindri::{JobStatus, ProofListInput};
+///
+/// let input = ProofListInput {
+///     date_created_after: Some(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap()),
+///     status: Some(JobStatus::Ready),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProofListInput {
+    /// Only proofs created after this time
+    pub date_created_after: Option<DateTime<Utc>>,
+    /// Only proofs created before this time
+    pub date_created_before: Option<DateTime<Utc>>,
+    /// Only proofs of circuits belonging to this project
+    pub project_name: Option<String>,
+    /// Only proofs with this status
+    pub status: Option<JobStatus>,
+}
+
+// Convert from our typed filters to the original type
+impl From<ProofListInput> for InternalProofListInput {
+    fn from(input: ProofListInput) -> Self {
+        InternalProofListInput {
+            date_created_after: input.date_created_after.map(|date| Some(date.to_rfc3339())),
+            date_created_before: input
+                .date_created_before
+                .map(|date| Some(date.to_rfc3339())),
+            project_name: input.project_name.map(Some),
+            status: input.status.map(Some),
+        }
+    }
+}
+
+/// The Solidity source of a smart contract which verifies a circuit's proofs on-chain.
+///
+/// Returned by [`get_smart_contract_verifier`](crate::client::SindriClient::get_smart_contract_verifier).
+#[derive(Clone, Debug, PartialEq)]
+pub struct SmartContractVerifier {
+    /// ID of the circuit whose proofs the contract verifies
+    pub circuit_id: String,
+    /// Solidity source code of the contract
+    pub contract_code: String,
+}
+
+impl SmartContractVerifier {
+    /// Writes the contract source to a file, adding a `.sol` extension if the path has none.
+    ///
+    /// Returns the path of the written file.
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> Result<PathBuf, Error> {
+        let mut path = path.as_ref().to_path_buf();
+        if path.extension().is_none() {
+            path.set_extension("sol");
+        }
+        std::fs::write(&path, &self.contract_code)?;
+        Ok(path)
+    }
+}
+
+/// The number of proofs created within one bin of a [`ProofHistogram`], by current status.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofHistogramBin {
+    /// Start of the bin
+    pub bin: DateTime<Utc>,
+    pub queued: i64,
+    pub in_progress: i64,
+    pub ready: i64,
+    pub failed: i64,
+}
+
+impl ProofHistogramBin {
+    /// Returns the number of proofs in the bin, regardless of status.
+    pub fn total(&self) -> i64 {
+        self.queued + self.in_progress + self.ready + self.failed
+    }
+}
+
+/// Proof counts of the authenticated team over time, in bins of equal size.
+///
+/// Returned by [`get_proof_histogram`](crate::client::SindriClient::get_proof_histogram).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProofHistogram {
+    /// Length of each bin
+    pub bin_size: std::time::Duration,
+    /// Start of the histogram
+    pub start_time: DateTime<Utc>,
+    /// End of the histogram
+    pub end_time: DateTime<Utc>,
+    /// Bins in chronological order
+    pub bins: Vec<ProofHistogramBin>,
+}
+
+impl ProofHistogram {
+    /// Returns the number of proofs across all bins, regardless of status.
+    pub fn total(&self) -> i64 {
+        self.bins.iter().map(ProofHistogramBin::total).sum()
+    }
+}
+
+/// A status transition of a circuit or proof, as observed while watching the job.
+///
+/// These are the items yielded by [`watch_circuit`](crate::client::SindriClient::watch_circuit)
+/// and [`watch_proof`](crate::client::SindriClient::watch_proof).
+#[derive(Clone, Debug, PartialEq)]
+pub struct JobStatusUpdate {
+    /// ID of the circuit or proof
+    pub id: String,
+    /// Status of the job after the transition
+    pub status: JobStatus,
+    /// Time at which the transition was observed
+    pub timestamp: DateTime<Utc>,
+    /// Seconds the job has spent queued so far, if reported
+    pub queue_time_sec: Option<f64>,
+    /// Seconds the job has spent processing so far, if reported
+    pub compute_time_sec: Option<f64>,
+}
+
+impl JobStatusUpdate {
+    /// Returns whether the job has finished processing (either ready or failed).
+    pub fn is_finished(&self) -> bool {
+        matches!(self.status, JobStatus::Ready | JobStatus::Failed)
+    }
+
+    /// Returns the total seconds the job has spent queued and computing, if reported.
+    pub fn job_age_sec(&self) -> Option<f64> {
+        match (self.queue_time_sec, self.compute_time_sec) {
+            (None, None) => None,
+            (queue, compute) => Some(queue.unwrap_or_default() + compute.unwrap_or_default()),
+        }
+    }
+}
+
+/// Progress of a project upload, as reported to the callback set with
+/// [`with_upload_progress`](crate::client::SindriClient::with_upload_progress).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UploadProgress {
+    /// Bytes of the project archive sent so far in this attempt
+    pub bytes_sent: u64,
+    /// Size of the project archive in bytes
+    pub total_bytes: u64,
+    /// Upload attempt, starting at 1 and incremented whenever a failed upload is retried
+    pub attempt: u32,
+}
+
+/// Report of what packaging a project directory would upload, as returned by
+/// [`plan_package`](crate::client::SindriClient::plan_package).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PackagePlan {
+    /// Files added to the project archive, in archive order
+    pub included: Vec<PlannedFile>,
+    /// Files and directories left out of the project archive, in walk order
+    pub excluded: Vec<ExcludedPath>,
+    /// Projected size of the compressed project archive in bytes
+    pub compressed_size: u64,
+    /// Largest compressed size in bytes which may be uploaded
+    pub max_size: u64,
+}
+
+impl PackagePlan {
+    /// Total uncompressed size of the included files in bytes.
+    pub fn total_size(&self) -> u64 {
+        self.included.iter().map(|file| file.size).sum()
+    }
+
+    /// Whether the project archive would be rejected for exceeding the maximum size.
+    pub fn exceeds_max_size(&self) -> bool {
+        self.compressed_size > self.max_size
+    }
+}
+
+/// A file added to the project archive.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlannedFile {
+    /// Path of the file relative to the project directory
+    pub path: PathBuf,
+    /// Size of the file in bytes, or 0 for a preserved symbolic link
+    pub size: u64,
+}
+
+/// A file or directory left out of the project archive. Directories are reported once,
+/// without their contents.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExcludedPath {
+    /// Path relative to the project directory
+    pub path: PathBuf,
+    /// Whether the path is a directory
+    pub is_dir: bool,
+    /// Rule which excluded the path
+    pub reason: ExclusionReason,
+}
+
+/// Rule which excluded a path from the project archive.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExclusionReason {
+    /// Matched a pattern of an ignore file, e.g. `.sindriignore` or `.gitignore`
+    IgnoreRule {
+        /// Path of the ignore file
+        file: PathBuf,
+        /// Line of the pattern in the ignore file, starting at 1
+        line: Option<usize>,
+        /// Pattern as written in the ignore file
+        pattern: String,
+    },
+    /// Matched an `exclude` pattern of the [`PackageOptions`](crate::client::PackageOptions)
+    ExcludePattern {
+        /// Pattern as written in the package options
+        pattern: String,
+    },
+    /// Git repository data, which is never packaged
+    GitDirectory,
+    /// Hidden file or directory, whose name starts with `.`
+    Hidden,
+    /// Neither a regular file nor a directory, e.g. a symbolic link to a directory when links
+    /// are not followed
+    NotRegularFile,
+    /// Excluded for a reason which could not be determined, e.g. by a rule from outside
+    /// the project such as the global git excludes file
+    Other,
+}
+
+impl fmt::Display for ExclusionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExclusionReason::IgnoreRule {
+                file,
+                line: Some(line),
+                pattern,
+            } => write!(f, "`{}` ({}:{})", pattern, file.display(), line),
+            ExclusionReason::IgnoreRule { file, pattern, .. } => {
+                write!(f, "`{}` ({})", pattern, file.display())
+            }
+            ExclusionReason::ExcludePattern { pattern } => {
+                write!(f, "`{}` (package options)", pattern)
+            }
+            ExclusionReason::GitDirectory => f.write_str("git repository data"),
+            ExclusionReason::Hidden => f.write_str("hidden"),
+            ExclusionReason::NotRegularFile => f.write_str("not a regular file"),
+            ExclusionReason::Other => f.write_str("excluded, reason unknown"),
+        }
+    }
+}
+
 pub trait ProofInfo {
-    fn get_proof_as_serde_json(&self) -> Result<serde_json::Value, Box<dyn std::error::Error>>;
-    fn get_proof_as_bytes(&self) -> Result<Vec<u8>, Box<dyn std::error::Error>>;
+    fn get_proof_as_serde_json(&self) -> Result<serde_json::Value, Error>;
+    fn get_proof_as_bytes(&self) -> Result<Vec<u8>, Error>;
+    fn get_smart_contract_calldata(&self) -> Result<SmartContractCalldata, Error>;
 }
 
 impl ProofInfo for ProofInfoResponse {
     /// Returns the unwrapped proof data as a serde_json::Value
-    fn get_proof_as_serde_json(&self) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+    fn get_proof_as_serde_json(&self) -> Result<serde_json::Value, Error> {
         self.proof
             .clone()
             .flatten()
-            .ok_or_else(|| "Proof field is not populated".into())
+            .ok_or_else(|| Error::proof_data("Proof field is not populated"))
     }
 
     /// Extracts proof bytes from base64-encoded proof data.
     /// Only applicable for Halo2 and Sp1 circuits.
-    fn get_proof_as_bytes(&self) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    fn get_proof_as_bytes(&self) -> Result<Vec<u8>, Error> {
         let proof_value = self.get_proof_as_serde_json()?;
         let circuit_type = self.circuit_type;
 
@@ -217,34 +495,170 @@ impl ProofInfo for ProofInfoResponse {
             CircuitType::Halo2 | CircuitType::Sp1 => {
                 let proof_str = extract_single_value(&proof_value)?
                     .as_str()
-                    .ok_or("Failed to convert proof to string")?;
+                    .ok_or_else(|| Error::proof_data("Failed to convert proof to string"))?;
 
                 general_purpose::STANDARD
                     .decode(proof_str)
-                    .map_err(|e| format!("Failed to decode base64: {}", e).into())
+                    .map_err(|e| Error::ProofData {
+                        message: format!("Failed to decode base64: {}", e),
+                        source: Some(Box::new(e)),
+                    })
             }
-            _ => Err(format!(
-                "Proof extraction as bytesnot supported for circuit type: {}",
+            _ => Err(Error::proof_data(format!(
+                "Proof extraction as bytes not supported for circuit type: {}",
                 circuit_type
-            )
-            .into()),
+            ))),
+        }
+    }
+
+    /// Parses the calldata for the circuit's smart contract verifier.
+    /// Only available for Groth16 proofs with `has_smart_contract_calldata` set.
+    fn get_smart_contract_calldata(&self) -> Result<SmartContractCalldata, Error> {
+        let calldata = self
+            .smart_contract_calldata
+            .clone()
+            .flatten()
+            .ok_or_else(|| Error::proof_data("Smart contract calldata is not populated"))?;
+        calldata.parse()
+    }
+}
+
+/// The arguments of a Groth16 verifier contract's `verifyProof` function.
+///
+/// Every value is a 256-bit word formatted as `0x`-prefixed, zero-padded lowercase hex,
+/// which can be parsed directly by `U256` implementations like `alloy::primitives::U256`.
+///
+/// # Examples
+///
+/// ```
+/// use sindri::SmartContractCalldata;
+///
+/// let calldata: SmartContractCalldata = r#"["0x1", "0x2"],[["0x3", "0x4"],["0x5", "0x6"]],["0x7", "0x8"],["0x9"]"#
+///     .parse()
+///     .unwrap();
+/// assert_eq!(calldata.public_inputs.len(), 1);
+/// assert_eq!(calldata.to_abi_bytes().unwrap().len(), 9 * 32);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SmartContractCalldata {
+    /// The `A` point of the proof
+    pub a: [String; 2],
+    /// The `B` point of the proof, with its coordinates already in calldata order
+    pub b: [[String; 2]; 2],
+    /// The `C` point of the proof
+    pub c: [String; 2],
+    /// The public inputs of the proof
+    pub public_inputs: Vec<String>,
+}
+
+impl SmartContractCalldata {
+    /// Returns the arguments ABI-encoded as `(uint256[2], uint256[2][2], uint256[2], uint256[N])`,
+    /// i.e. the calldata of `verifyProof` without its function selector.
+    ///
+    /// Words need not be zero-padded, but each must be `0x`-prefixed hex of at most 64 digits,
+    /// otherwise an [`Error::ProofData`] is returned.
+    pub fn to_abi_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::with_capacity((8 + self.public_inputs.len()) * 32);
+        for word in self
+            .a
+            .iter()
+            .chain(self.b.iter().flatten())
+            .chain(self.c.iter())
+            .chain(self.public_inputs.iter())
+        {
+            bytes.extend(decode_word(word)?);
         }
+        Ok(bytes)
+    }
+}
+
+impl std::str::FromStr for SmartContractCalldata {
+    type Err = Error;
+
+    /// Parses calldata in the `[a],[b],[c],[inputs]` format exported by snarkjs.
+    fn from_str(calldata: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<serde_json::Value> = serde_json::from_str(&format!("[{}]", calldata.trim()))
+            .map_err(|e| Error::ProofData {
+                message: format!("Failed to parse smart contract calldata: {}", e),
+                source: Some(Box::new(e)),
+            })?;
+        let [a, b, c, public_inputs] =
+            <[serde_json::Value; 4]>::try_from(parts).map_err(|parts| {
+                Error::proof_data(format!(
+                    "Smart contract calldata must have 4 parts, found {}",
+                    parts.len()
+                ))
+            })?;
+        let words = |value: &serde_json::Value| -> Result<Vec<String>, Error> {
+            value
+                .as_array()
+                .ok_or_else(|| Error::proof_data("Smart contract calldata part must be an array"))?
+                .iter()
+                .map(|word| {
+                    word.as_str()
+                        .ok_or_else(|| {
+                            Error::proof_data(format!("Invalid 256-bit hex value: {}", word))
+                        })
+                        .and_then(normalize_word)
+                })
+                .collect()
+        };
+        let pair = |value: &serde_json::Value| -> Result<[String; 2], Error> {
+            <[String; 2]>::try_from(words(value)?).map_err(|_| {
+                Error::proof_data("Smart contract calldata point must have 2 coordinates")
+            })
+        };
+        let b = b
+            .as_array()
+            .ok_or_else(|| Error::proof_data("Smart contract calldata part must be an array"))?
+            .iter()
+            .map(pair)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(SmartContractCalldata {
+            a: pair(&a)?,
+            b: <[[String; 2]; 2]>::try_from(b).map_err(|_| {
+                Error::proof_data("Smart contract calldata point must have 2 coordinates")
+            })?,
+            c: pair(&c)?,
+            public_inputs: words(&public_inputs)?,
+        })
+    }
+}
+
+/// Formats a hex-encoded calldata value as a zero-padded 256-bit word.
+fn normalize_word(value: &str) -> Result<String, Error> {
+    let invalid = || Error::proof_data(format!("Invalid 256-bit hex value: {:?}", value));
+    let digits = value.strip_prefix("0x").ok_or_else(invalid)?;
+    if digits.is_empty() || digits.len() > 64 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+    Ok(format!("0x{:0>64}", digits.to_ascii_lowercase()))
+}
+
+/// Decodes a hex-encoded calldata value into its 32 big-endian bytes.
+fn decode_word(word: &str) -> Result<[u8; 32], Error> {
+    let word = normalize_word(word)?;
+    let digits = &word.as_bytes()[2..];
+    let mut bytes = [0; 32];
+    for (byte, pair) in bytes.iter_mut().zip(digits.chunks_exact(2)) {
+        // The digits were checked to be ASCII hex by `normalize_word`
+        let pair = std::str::from_utf8(pair).unwrap_or("00");
+        *byte = u8::from_str_radix(pair, 16).unwrap_or_default();
     }
+    Ok(bytes)
 }
 
 /// Extracts the value from a JSON object that contains exactly one field.
 /// Returns an error if the JSON is not an object or has more/less than one field.
-fn extract_single_value(
-    value: &serde_json::Value,
-) -> Result<&serde_json::Value, Box<dyn std::error::Error>> {
+fn extract_single_value(value: &serde_json::Value) -> Result<&serde_json::Value, Error> {
     match value {
         serde_json::Value::Object(map) => {
             if map.len() != 1 {
-                return Err("JSON object must have exactly one field".into());
+                return Err(Error::proof_data("JSON object must have exactly one field"));
             }
             Ok(map.values().next().unwrap())
         }
-        _ => Err("Input must be a JSON object".into()),
+        _ => Err(Error::proof_data("Input must be a JSON object")),
     }
 }
 
@@ -275,10 +689,12 @@ mod tests {
     fn test_circuit_info_getters() {
         let circuit_info = create_test_response();
         assert_eq!(circuit_info.id(), "1234");
+        assert_eq!(circuit_info.circuit_type(), CircuitType::Noir);
         assert_eq!(circuit_info.compute_time_sec(), Some(42.5));
         assert_eq!(circuit_info.date_created(), "2025-01-01");
         assert_eq!(circuit_info.error(), Some("test error".to_string()));
         assert!(circuit_info.finished_processing());
+        assert!(!circuit_info.has_smart_contract_verifier());
         assert_eq!(
             circuit_info.meta(),
             &HashMap::from([("key".to_string(), "value".to_string())])
@@ -345,6 +761,25 @@ mod tests {
         assert_eq!(proof_input, original);
     }
 
+    #[test]
+    fn test_proof_list_input_conversion() {
+        use chrono::TimeZone;
+
+        let input = ProofListInput {
+            date_created_after: Some(Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap()),
+            status: Some(JobStatus::Ready),
+            ..Default::default()
+        };
+        let internal: InternalProofListInput = input.into();
+        assert_eq!(
+            internal.date_created_after,
+            Some(Some("2025-01-02T03:04:05+00:00".to_string()))
+        );
+        assert_eq!(internal.date_created_before, None);
+        assert_eq!(internal.project_name, None);
+        assert_eq!(internal.status, Some(Some(JobStatus::Ready)));
+    }
+
     #[test]
     fn test_proof_into_json_success() {
         let json_value = serde_json::json!({"pi_a": "256", "pi_b": "256"});
@@ -369,6 +804,76 @@ mod tests {
         assert_eq!(error.to_string(), "Proof field is not populated");
     }
 
+    #[test]
+    fn test_smart_contract_calldata() {
+        // Calldata as exported by snarkjs, including the trailing newline
+        let proof_response = ProofInfoResponse {
+            smart_contract_calldata: Some(Some(
+                r#"["0x08ecf2cc", "0x27D5"],[["0x14c3", "0x1246"],["0x2535", "0x2e52"]],["0x0a37", "0x2f19"],["0x02"]
+"#
+                .to_string(),
+            )),
+            has_smart_contract_calldata: Some(true),
+            ..Default::default()
+        };
+
+        let calldata = proof_response.get_smart_contract_calldata().unwrap();
+        assert_eq!(calldata.a[0], format!("0x{:0>64}", "08ecf2cc"));
+        assert_eq!(calldata.a[1], format!("0x{:0>64}", "27d5"));
+        assert_eq!(calldata.b[1][0], format!("0x{:0>64}", "2535"));
+        assert_eq!(calldata.public_inputs, vec![format!("0x{:0>64}", "02")]);
+
+        let bytes = calldata.to_abi_bytes().unwrap();
+        assert_eq!(bytes.len(), 9 * 32);
+        assert_eq!(&bytes[28..32], &[0x08, 0xec, 0xf2, 0xcc]);
+        assert_eq!(bytes[bytes.len() - 1], 0x02);
+        assert!(bytes[bytes.len() - 32..bytes.len() - 1]
+            .iter()
+            .all(|b| *b == 0));
+    }
+
+    #[test]
+    fn test_smart_contract_calldata_built_by_hand() {
+        // Words of a hand-built value are padded when encoded
+        let mut calldata = SmartContractCalldata {
+            a: ["0x1".into(), "0x2".into()],
+            b: [["0x3".into(), "0x4".into()], ["0x5".into(), "0x6".into()]],
+            c: ["0x7".into(), "0x8".into()],
+            public_inputs: vec!["0xFF".into()],
+        };
+        let bytes = calldata.to_abi_bytes().unwrap();
+        assert_eq!(bytes.len(), 9 * 32);
+        assert_eq!(bytes[31], 0x01);
+        assert_eq!(bytes[9 * 32 - 1], 0xff);
+
+        calldata.a[0] = "0x".to_string() + &"1".repeat(65);
+        let error = calldata.to_abi_bytes().unwrap_err();
+        assert!(error.to_string().starts_with("Invalid 256-bit hex value"));
+        calldata.a[0] = "1".to_string();
+        assert!(calldata.to_abi_bytes().is_err());
+    }
+
+    #[test]
+    fn test_smart_contract_calldata_invalid() {
+        let proof_response = ProofInfoResponse::default();
+        let error = proof_response.get_smart_contract_calldata().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Smart contract calldata is not populated"
+        );
+
+        let error = r#"["0x1", "0x2"],["0x3"]"#.parse::<SmartContractCalldata>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Smart contract calldata must have 4 parts, found 2"
+        );
+
+        let error = r#"["0x1", "12"],[["0x3", "0x4"],["0x5", "0x6"]],["0x7", "0x8"],[]"#
+            .parse::<SmartContractCalldata>()
+            .unwrap_err();
+        assert_eq!(error.to_string(), "Invalid 256-bit hex value: \"12\"");
+    }
+
     #[test]
     fn test_extract_single_value() {
         // Test successful case
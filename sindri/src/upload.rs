@@ -0,0 +1,285 @@
+//! These methods upload project archives to Sindri, streaming them from disk.
+//!
+//! Streamed requests cannot pass through the retry middleware, so uploads which fail with a
+//! transient error are retried here by reopening the archive and rebuilding the request.
+
+use std::{collections::HashMap, fmt, sync::Arc, time::SystemTime};
+
+use futures::{stream, StreamExt, TryStreamExt};
+use reqwest::Body;
+use reqwest_retry::{RetryDecision, RetryPolicy};
+use sindri_openapi::{apis::circuits_api::circuit_create, models::CircuitInfoResponse};
+use tokio_util::io::ReaderStream;
+use tracing::{debug, info};
+
+use crate::{client::SindriClient, types::UploadProgress, utils::ProjectArchive, Error};
+
+// Multipart form data layout of project uploads
+const MULTIPART_BOUNDARY: &str = "----------------------------4ebf00fbcf09";
+const UPLOAD_FILENAME: &str = "rust_sdk_upload.tar.gz";
+
+// Size of the chunks read from the project archive, which bounds memory use while uploading
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Callback set with [`with_upload_progress`](SindriClient::with_upload_progress).
+#[derive(Clone)]
+pub(crate) struct UploadProgressCallback(pub(crate) Arc<dyn Fn(UploadProgress) + Send + Sync>);
+
+impl fmt::Debug for UploadProgressCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("UploadProgressCallback")
+    }
+}
+
+/// Returns the multipart form data which precedes and follows the project archive.
+fn multipart_envelope(
+    meta: Option<HashMap<String, String>>,
+    tags: Option<Vec<String>>,
+) -> Result<(Vec<u8>, Vec<u8>), Error> {
+    let boundary = MULTIPART_BOUNDARY;
+    let prefix = format!(
+        "--{boundary}\r\n\
+        Content-Disposition: form-data; name=\"files\"; filename=\"{UPLOAD_FILENAME}\"\r\n\
+        \r\n",
+    )
+    .into_bytes();
+
+    let mut suffix = format!("--{boundary}--\r\n").into_bytes(); // End of files
+    for tag in tags.unwrap_or_default() {
+        suffix.extend_from_slice(
+            format!(
+                "--{boundary}\r\n\
+                Content-Disposition: form-data; name=\"tags\"\r\n\
+                \r\n\
+                {tag}\r\n"
+            )
+            .as_bytes(),
+        );
+        suffix.extend_from_slice(format!("--{boundary}--\r\n").as_bytes()); // End of tag
+    }
+    if let Some(meta) = meta {
+        let meta_json = serde_json::to_string(&meta)?;
+        suffix.extend_from_slice(
+            format!(
+                "--{boundary}\r\n\
+                Content-Disposition: form-data; name=\"meta\"\r\n\
+                Content-Type: application/json\r\n\
+                \r\n\
+                {meta_json}\r\n"
+            )
+            .as_bytes(),
+        );
+        suffix.extend_from_slice(format!("--{boundary}--\r\n").as_bytes()); // End of meta
+    }
+    Ok((prefix, suffix))
+}
+
+impl SindriClient {
+    /// Uploads a project archive to create a new circuit.
+    ///
+    /// The archive is streamed from disk in chunks of `UPLOAD_CHUNK_SIZE` bytes. Uploads which
+    /// fail with a transient error are retried with the backoff policy of the client.
+    pub(crate) async fn upload_project(
+        &self,
+        archive: &ProjectArchive,
+        meta: Option<HashMap<String, String>>,
+        tags: Option<Vec<String>>,
+    ) -> Result<CircuitInfoResponse, Error> {
+        let (prefix, suffix) = multipart_envelope(meta, tags)?;
+        let start_time = SystemTime::now();
+        let mut attempt = 1;
+        loop {
+            let body = self
+                .multipart_body(archive, &prefix, &suffix, attempt)
+                .await?;
+            let error = match circuit_create(&self.config, body, MULTIPART_BOUNDARY).await {
+                Ok(response) => return Ok(response),
+                Err(e) => Error::from(e),
+            };
+            if !error.is_transient() {
+                return Err(error);
+            }
+            match self
+                .upload_retry_policy
+                .should_retry(start_time, attempt - 1)
+            {
+                RetryDecision::Retry { execute_after } => {
+                    let wait = execute_after
+                        .duration_since(SystemTime::now())
+                        .unwrap_or_default();
+                    info!(
+                        "Upload attempt {} failed ({}), retrying in {:?}",
+                        attempt, error, wait
+                    );
+                    tokio::time::sleep(wait).await;
+                    attempt += 1;
+                }
+                RetryDecision::DoNotRetry => return Err(error),
+            }
+        }
+    }
+
+    /// Builds the multipart body of one upload attempt, reading the archive from the start.
+    async fn multipart_body(
+        &self,
+        archive: &ProjectArchive,
+        prefix: &[u8],
+        suffix: &[u8],
+        attempt: u32,
+    ) -> Result<Body, Error> {
+        debug!(
+            "Streaming {} byte archive from {}",
+            archive.size(),
+            archive.path().display()
+        );
+        let file = tokio::fs::File::open(archive.path()).await?;
+        let total_bytes = archive.size();
+        let progress = self.upload_progress.clone();
+        let mut bytes_sent = 0;
+        let chunks =
+            ReaderStream::with_capacity(file, UPLOAD_CHUNK_SIZE).inspect_ok(move |chunk| {
+                bytes_sent += chunk.len() as u64;
+                if let Some(UploadProgressCallback(callback)) = &progress {
+                    callback(UploadProgress {
+                        bytes_sent,
+                        total_bytes,
+                        attempt,
+                    });
+                }
+            });
+        let parts = stream::iter([Ok(prefix.to_vec().into())])
+            .chain(chunks)
+            .chain(stream::iter([Ok(suffix.to_vec().into())]));
+
+        #[cfg(not(any(feature = "record", feature = "replay")))]
+        {
+            Ok(Body::wrap_stream(parts))
+        }
+        #[cfg(any(feature = "record", feature = "replay"))]
+        {
+            // Omit streaming for integration tests since VCR requires clonable requests
+            let buffer = parts
+                .try_fold(Vec::new(), |mut buffer, part| async move {
+                    buffer.extend_from_slice(&part);
+                    Ok(buffer)
+                })
+                .await?;
+            Ok(Body::from(buffer))
+        }
+    }
+}
+
+// These tests inspect the streamed request, whereas the record and replay features buffer
+// and compress the body and leave retries to the middleware
+#[cfg(all(test, not(any(feature = "record", feature = "replay"))))]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    use crate::{types::CircuitInfo, BoojumCircuitInfoResponse};
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, Request, ResponseTemplate,
+    };
+
+    fn circuit_response() -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(CircuitInfoResponse::Boojum(Box::new(
+            BoojumCircuitInfoResponse {
+                circuit_id: "test_circuit_123".to_string(),
+                ..Default::default()
+            },
+        )))
+    }
+
+    fn test_archive(dir: &tempfile::TempDir, size: usize) -> ProjectArchive {
+        let path = dir.path().join("project.tar.gz");
+        let contents: Vec<u8> = (0..size).map(|i| (i % 251) as u8).collect();
+        std::fs::write(&path, contents).unwrap();
+        ProjectArchive::from_file(path).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_upload_project_streams_with_progress() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/circuit/create"))
+            .respond_with(circuit_response())
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let archive = test_archive(&temp_dir, 3 * UPLOAD_CHUNK_SIZE + 10);
+        let updates = Arc::new(Mutex::new(Vec::new()));
+        let client = {
+            let updates = updates.clone();
+            SindriClient::default()
+                .with_base_url(mock_server.uri())
+                .with_upload_progress(move |progress| updates.lock().unwrap().push(progress))
+        };
+
+        let tags = Some(vec!["latest".to_string()]);
+        let circuit = client.upload_project(&archive, None, tags).await.unwrap();
+        assert_eq!(circuit.id(), "test_circuit_123");
+
+        let updates = updates.lock().unwrap().clone();
+        assert!(updates.len() > 1);
+        assert_eq!(
+            updates.last(),
+            Some(&UploadProgress {
+                bytes_sent: archive.size(),
+                total_bytes: archive.size(),
+                attempt: 1,
+            })
+        );
+
+        // The archive is sent unaltered between the multipart headers and trailers
+        let requests = mock_server.received_requests().await.unwrap();
+        let (prefix, suffix) = multipart_envelope(None, Some(vec!["latest".to_string()])).unwrap();
+        let expected = [prefix, std::fs::read(archive.path()).unwrap(), suffix].concat();
+        assert_eq!(requests[0].body, expected);
+    }
+
+    #[tokio::test]
+    async fn test_upload_project_retries_transient_errors() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/circuit/create"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/circuit/create"))
+            .respond_with(circuit_response())
+            .mount(&mock_server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let archive = test_archive(&temp_dir, 1000);
+        let attempts = Arc::new(Mutex::new(Vec::new()));
+        let client = {
+            let attempts = attempts.clone();
+            SindriClient::builder()
+                .retry_bounds(
+                    std::time::Duration::from_millis(10),
+                    std::time::Duration::from_millis(10),
+                )
+                .build()
+                .unwrap()
+                .with_base_url(mock_server.uri())
+                .with_upload_progress(move |progress| {
+                    attempts.lock().unwrap().push(progress.attempt)
+                })
+        };
+
+        let circuit = client.upload_project(&archive, None, None).await.unwrap();
+        assert_eq!(circuit.id(), "test_circuit_123");
+
+        // The whole archive is sent again on the retry
+        let requests: Vec<Request> = mock_server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].body, requests[1].body);
+        assert_eq!(attempts.lock().unwrap().last(), Some(&2));
+    }
+}
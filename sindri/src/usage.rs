@@ -0,0 +1,180 @@
+//! These methods report how many proofs a team generates over time, e.g. for capacity
+//! planning or reconciling invoices.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use sindri_openapi::{apis::proof_histogram, models::ProofHistogramInput};
+
+use crate::{
+    client::SindriClient,
+    types::{ProofHistogram, ProofHistogramBin},
+    utils::parse_api_datetime,
+    Error,
+};
+
+/// Parses a timestamp returned by the histogram endpoint.
+fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, Error> {
+    parse_api_datetime(value)
+        .ok_or_else(|| Error::UnexpectedResponse(format!("invalid histogram timestamp: {}", value)))
+}
+
+/// Parses one bin of the histogram, which reports a count for each status that occurred.
+fn parse_bin(value: &serde_json::Value) -> Result<ProofHistogramBin, Error> {
+    let bin = value
+        .get("bin")
+        .and_then(serde_json::Value::as_str)
+        .ok_or_else(|| Error::UnexpectedResponse("histogram bin has no timestamp".to_string()))?;
+    let count = |status: &str| {
+        value
+            .get(status)
+            .and_then(serde_json::Value::as_i64)
+            .unwrap_or_default()
+    };
+    Ok(ProofHistogramBin {
+        bin: parse_timestamp(bin)?,
+        queued: count("queued"),
+        in_progress: count("in_progress"),
+        ready: count("ready"),
+        failed: count("failed"),
+    })
+}
+
+impl SindriClient {
+    /// Counts the proofs created by the authenticated team over a time range.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_time` - Start of the range
+    /// * `end_time` - Optional end of the range, defaulting to now
+    /// * `bin_size` - Optional length of each bin, with whole-second precision
+    ///
+    /// # Returns
+    ///
+    /// Returns the proof counts per status for each bin, in chronological order.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// use std::time::Duration;
+    /// use chrono::{TimeDelta, Utc};
+    /// use sindri::client::SindriClient;
+    ///
+    /// let client = SindriClient::default();
+    /// let start = Utc::now() - TimeDelta::days(7);
+    /// let one_day = Duration::from_secs(24 * 60 * 60);
+    /// let histogram = client
+    ///     .get_proof_histogram(start, None, Some(one_day))
+    ///     .await
+    ///     .unwrap();
+    /// for bin in histogram.bins {
+    ///     println!("{}: {} ready, {} failed", bin.bin.date_naive(), bin.ready, bin.failed);
+    /// }
+    /// # });
+    /// ```
+    pub async fn get_proof_histogram(
+        &self,
+        start_time: DateTime<Utc>,
+        end_time: Option<DateTime<Utc>>,
+        bin_size: Option<Duration>,
+    ) -> Result<ProofHistogram, Error> {
+        let bin_size = bin_size
+            .map(|size| match i64::try_from(size.as_secs()) {
+                Ok(seconds) if seconds > 0 => Ok(seconds),
+                _ => Err(Error::Validation(
+                    "Histogram bin size must be at least one second".to_string(),
+                )),
+            })
+            .transpose()?;
+        let input = ProofHistogramInput {
+            bin_size,
+            start_time: start_time.to_rfc3339(),
+            end_time: end_time.map(|end_time| Some(end_time.to_rfc3339())),
+        };
+        let response = proof_histogram(&self.config, input).await?;
+        Ok(ProofHistogram {
+            bin_size: Duration::from_secs(response.bin_size.max(0) as u64),
+            start_time: parse_timestamp(&response.start_time)?,
+            end_time: parse_timestamp(&response.end_time)?,
+            bins: response
+                .data
+                .iter()
+                .map(parse_bin)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use sindri_openapi::models::ProofHistogramResponse;
+    use wiremock::{
+        matchers::{body_json, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[tokio::test]
+    async fn test_get_proof_histogram() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/proof/histogram"))
+            .and(body_json(serde_json::json!({
+                "bin_size": 86400,
+                "start_time": "2025-01-01T00:00:00+00:00",
+            })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(ProofHistogramResponse {
+                    bin_size: 86400,
+                    data: vec![
+                        serde_json::json!({"bin": "2025-01-01T00:00:00Z", "ready": 2, "failed": 1}),
+                        serde_json::json!({"bin": "2025-01-02T00:00:00Z", "queued": 4, "in_progress": 3}),
+                    ],
+                    start_time: "2025-01-01T00:00:00Z".to_string(),
+                    end_time: "2025-01-03T00:00:00Z".to_string(),
+                }),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = SindriClient::default().with_base_url(mock_server.uri());
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let histogram = client
+            .get_proof_histogram(start, None, Some(Duration::from_secs(86400)))
+            .await
+            .unwrap();
+
+        assert_eq!(histogram.bin_size, Duration::from_secs(86400));
+        assert_eq!(histogram.start_time, start);
+        assert_eq!(
+            histogram.bins[1],
+            ProofHistogramBin {
+                bin: Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap(),
+                queued: 4,
+                in_progress: 3,
+                ready: 0,
+                failed: 0,
+            }
+        );
+        assert_eq!(histogram.total(), 10);
+    }
+
+    #[tokio::test]
+    async fn test_get_proof_histogram_invalid_bin_size() {
+        let client = SindriClient::default();
+        let error = client
+            .get_proof_histogram(Utc::now(), None, Some(Duration::from_millis(500)))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::Validation(_)));
+    }
+
+    #[test]
+    fn test_parse_bin_missing_timestamp() {
+        let error = parse_bin(&serde_json::json!({"ready": 1})).unwrap_err();
+        assert!(matches!(error, Error::UnexpectedResponse(_)));
+    }
+}
@@ -1,29 +1,54 @@
 //! Utility functions for Sindri Rust SDK (mainly local file managers called by client methods)
 
-use std::{error::Error, io::Read, path::Path};
-
+use std::{
+    collections::{BTreeMap, HashMap},
+    ffi::OsStr,
+    io::{BufWriter, Read, Write},
+    path::{Component, Path, PathBuf},
+};
+
+use chrono::{DateTime, NaiveDate, Utc};
 #[cfg(feature = "rich-terminal")]
 use console::style;
-use flate2::{write::GzEncoder, Compression};
-use ignore::WalkBuilder;
+use flate2::{Compression, GzBuilder};
+use ignore::{
+    gitignore::{Gitignore, GitignoreBuilder},
+    Match, WalkBuilder,
+};
 #[cfg(feature = "rich-terminal")]
 use indicatif::{ProgressBar, ProgressStyle};
+use sha2::{Digest, Sha256};
+use tar::HeaderMode;
+use tempfile::TempPath;
+use tracing::{info, warn};
+
+use crate::{
+    client::{PackageOptions, SymlinkMode},
+    manifest::{validate_manifest_against, SindriManifest},
+    types::{ExcludedPath, ExclusionReason, PackagePlan, PlannedFile},
+    Error,
+};
 
-// Global recommended maximum on circuit uploads
-const MAX_PROJECT_SIZE: usize = 8 * 1024 * 1024 * 1024; // 8GB
+/// Global recommended maximum size of circuit uploads in bytes.
+pub const MAX_PROJECT_SIZE: u64 = 8 * 1024 * 1024 * 1024; // 8GB
 
 // Designated names for special purpose files
 pub const SINDRI_IGNORE_FILENAME: &str = ".sindriignore";
 pub const SINDRI_MANIFEST_FILENAME: &str = "sindri.json";
+const GIT_DIR_NAME: &str = ".git";
+
+/// Key of the circuit `meta` entry which records the digest of the deployed project.
+pub const PROJECT_DIGEST_META_KEY: &str = "project_digest";
 #[cfg(feature = "rich-terminal")]
 pub const CLOCK_TICKS: [&str; 12] = [
     "  🕛 ", "  🕐 ", "  🕑 ", "  🕒 ", "  🕓 ", "  🕔 ", "  🕕 ", "  🕖 ", "  🕗 ", "  🕘 ",
     "  🕙 ", "  🕚 ",
 ];
 
-/// Formats bytes into human readable string with appropriate unit
-#[cfg(feature = "rich-terminal")]
-fn format_size(bytes: usize) -> String {
+/// Formats a number of bytes for display, e.g. `1.50 MB`.
+///
+/// Units are multiples of 1024 bytes, labelled `KB`, `MB` and `GB`.
+pub fn format_size(bytes: u64) -> String {
     const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
     let mut size = bytes as f64;
     let mut unit_index = 0;
@@ -34,7 +59,7 @@ fn format_size(bytes: usize) -> String {
     }
 
     if unit_index == 0 {
-        format!("{} {}", size as usize, UNITS[unit_index])
+        format!("{} {}", bytes, UNITS[unit_index])
     } else {
         format!("{:.2} {}", size, UNITS[unit_index])
     }
@@ -68,40 +93,378 @@ impl ClockProgressBar {
     }
 }
 
+/// A compressed project on disk, ready to be uploaded to Sindri.
+///
+/// Uploads stream the archive from disk rather than holding it in memory, and reopen it for
+/// each attempt so that failed uploads can be retried. Archives prepared from a project
+/// directory are spooled to a temporary file, which is deleted when the archive is dropped.
+#[derive(Debug)]
+pub struct ProjectArchive {
+    path: PathBuf,
+    size: u64,
+    digest: String,
+    manifest: Option<SindriManifest>,
+    _spool: Option<TempPath>,
+}
+
+impl ProjectArchive {
+    /// Uses an existing archive file (e.g. a tarball supplied by the user) as is.
+    ///
+    /// The digest of such an archive is the digest of the file itself.
+    pub fn from_file(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        let path = path.into();
+        let mut hasher = Sha256::new();
+        let size = std::io::copy(&mut std::fs::File::open(&path)?, &mut hasher)?;
+        Ok(Self {
+            path,
+            size,
+            digest: format_digest(hasher),
+            manifest: None,
+            _spool: None,
+        })
+    }
+
+    /// Returns the path of the archive file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the size of the archive in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the SHA-256 content digest of the project, e.g. `sha256:9f86d0...`.
+    ///
+    /// The digest of a project directory covers the path, permissions and contents of every
+    /// packaged file, but not the name of the directory itself. Packaging the same source tree
+    /// always yields the same digest (and the same archive).
+    pub fn digest(&self) -> &str {
+        &self.digest
+    }
+
+    /// Returns the Sindri manifest of the project, if it was packaged from a directory.
+    pub fn manifest(&self) -> Option<&SindriManifest> {
+        self.manifest.as_ref()
+    }
+}
+
+/// Formats a finished SHA-256 digest as a `sha256:`-prefixed hex string.
+fn format_digest(hasher: Sha256) -> String {
+    format!("sha256:{:x}", hasher.finalize())
+}
+
+/// Reader which feeds everything read through it into a digest.
+struct DigestReader<'a, R> {
+    inner: R,
+    hasher: &'a mut Sha256,
+}
+
+impl<R: Read> Read for DigestReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.hasher.update(&buf[..read]);
+        Ok(read)
+    }
+}
+
+/// Kind of a project entry selected for packaging.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum EntryKind {
+    File,
+    Dir,
+    /// Symbolic link which is packaged as is, pointing to the given target
+    Symlink(PathBuf),
+    /// Neither a regular file, a directory nor a preserved link, e.g. a socket
+    Other,
+}
+
+/// Builds a matcher of `.gitignore` syntax patterns, which match paths relative to `dir`.
+fn pattern_matcher(dir: &Path, patterns: &[String]) -> Result<Gitignore, Error> {
+    let mut builder = GitignoreBuilder::new(dir);
+    for pattern in patterns {
+        builder.add_line(None, pattern).map_err(|e| {
+            Error::Validation(format!("Invalid package pattern \"{}\": {}", pattern, e))
+        })?;
+    }
+    builder
+        .build()
+        .map_err(|e| Error::Validation(format!("Invalid package patterns: {}", e)))
+}
+
+/// Returns whether a path relative to the project directory matches any of the patterns,
+/// either directly or through one of its parent directories.
+fn matches_pattern(matcher: &Gitignore, project_path: &Path, is_dir: bool) -> bool {
+    !project_path.as_os_str().is_empty()
+        && matcher
+            .matched_path_or_any_parents(project_path, is_dir)
+            .is_ignore()
+}
+
+/// Walks the project directory, selecting the paths to package according to `options`.
+/// Git repository data is always left out, since it may hold credentials and history which
+/// the project does not need.
+///
+/// Entries are returned in sorted order, which is the order of a depth-first walk visiting
+/// the entries of each directory by name.
+fn walk_project(
+    dir: &Path,
+    options: &PackageOptions,
+) -> Result<BTreeMap<PathBuf, EntryKind>, Error> {
+    let exclude = pattern_matcher(dir, &options.exclude)?;
+    let walker = |standard_filters: bool| {
+        let root = dir.to_path_buf();
+        let exclude = exclude.clone();
+        let mut builder = WalkBuilder::new(dir);
+        builder
+            .standard_filters(standard_filters)
+            .follow_links(options.symlinks == SymlinkMode::Follow)
+            .sort_by_file_name(|a, b| a.cmp(b))
+            .filter_entry(move |entry| {
+                if entry.file_name() == GIT_DIR_NAME {
+                    return false;
+                }
+                let is_dir = entry.file_type().is_some_and(|t| t.is_dir());
+                entry
+                    .path()
+                    .strip_prefix(&root)
+                    .map_or(true, |path| !matches_pattern(&exclude, path, is_dir))
+            });
+        if standard_filters {
+            builder
+                .add_custom_ignore_filename(SINDRI_IGNORE_FILENAME)
+                .hidden(!options.include_hidden)
+                .git_ignore(options.respect_gitignore)
+                .git_global(options.respect_gitignore)
+                .git_exclude(options.respect_gitignore);
+        }
+        builder.build()
+    };
+
+    let mut entries = BTreeMap::new();
+    for entry in walker(true) {
+        if let Some((path, kind)) = project_entry(entry, options.symlinks)? {
+            entries.insert(path, kind);
+        }
+    }
+
+    // Included paths are found by walking the project again without the ignore rules
+    if !options.include.is_empty() {
+        let include = pattern_matcher(dir, &options.include)?;
+        for entry in walker(false) {
+            let included = entry.as_ref().is_ok_and(|entry| {
+                let is_dir = entry.file_type().is_some_and(|t| t.is_dir());
+                entry
+                    .path()
+                    .strip_prefix(dir)
+                    .is_ok_and(|path| matches_pattern(&include, path, is_dir))
+            });
+            if !included {
+                continue;
+            }
+            if let Some((path, kind)) = project_entry(entry, options.symlinks)? {
+                for parent in path.ancestors().skip(1) {
+                    if parent == dir || entries.contains_key(parent) {
+                        break;
+                    }
+                    entries.insert(parent.to_path_buf(), EntryKind::Dir);
+                }
+                entries.insert(path, kind);
+            }
+        }
+    }
+    Ok(entries)
+}
+
+/// Classifies an entry of the project walk, skipping the project directory itself and
+/// entries which could not be read.
+fn project_entry(
+    entry: Result<ignore::DirEntry, ignore::Error>,
+    symlinks: SymlinkMode,
+) -> Result<Option<(PathBuf, EntryKind)>, Error> {
+    let entry = match entry {
+        Ok(entry) if entry.depth() > 0 => entry,
+        Ok(_) => return Ok(None),
+        Err(e) => {
+            warn!("Skipping unreadable project path: {}", e);
+            return Ok(None);
+        }
+    };
+    let kind = if entry.path_is_symlink() && symlinks == SymlinkMode::Error {
+        return Err(Error::Packaging(format!(
+            "Project contains a symbolic link at {}, which is not allowed by the package options",
+            entry.path().display()
+        )));
+    } else if entry.path_is_symlink() && symlinks == SymlinkMode::Preserve {
+        let target = std::fs::read_link(entry.path())?;
+        if !stays_within_project(&target, entry.depth() - 1) {
+            return Err(Error::Packaging(format!(
+                "Symbolic link at {} points to {}, which is outside of the project",
+                entry.path().display(),
+                target.display()
+            )));
+        }
+        EntryKind::Symlink(target)
+    } else if entry.path_is_symlink() && symlinks == SymlinkMode::FollowFiles {
+        // The walker does not follow links in this mode, so links to files are resolved here
+        if entry.path().is_file() {
+            EntryKind::File
+        } else {
+            EntryKind::Other
+        }
+    } else {
+        match entry.file_type() {
+            Some(file_type) if file_type.is_dir() => EntryKind::Dir,
+            Some(file_type) if file_type.is_file() => EntryKind::File,
+            _ => EntryKind::Other,
+        }
+    };
+    Ok(Some((entry.into_path(), kind)))
+}
+
+/// Returns whether a relative link target resolves within the project, for a link whose
+/// directory is `depth` levels below the project directory.
+fn stays_within_project(target: &Path, depth: usize) -> bool {
+    let mut depth = Some(depth);
+    for component in target.components() {
+        depth = match component {
+            Component::Normal(_) => depth.map(|depth| depth + 1),
+            Component::CurDir => depth,
+            Component::ParentDir => depth.and_then(|depth| depth.checked_sub(1)),
+            Component::RootDir | Component::Prefix(_) => None,
+        };
+    }
+    depth.is_some()
+}
+
+/// Writes the project files into `writer` as a gzipped tarball, returning the writer along
+/// with the digest of the project contents.
+fn write_project_archive<W: Write>(
+    dir: &Path,
+    options: &PackageOptions,
+    writer: W,
+) -> Result<(W, String), Error> {
+    // Fix the gzip header fields which would otherwise vary between runs
+    let enc = GzBuilder::new()
+        .mtime(0)
+        .operating_system(255)
+        .write(writer, Compression::default());
+    let mut tar = tar::Builder::new(enc);
+    let mut hasher = Sha256::new();
+
+    for (path, kind) in walk_project(dir, options)? {
+        let project_path = path.strip_prefix(dir).map_err(strip_error)?;
+        let relative_path = if dir == Path::new(".") {
+            Path::new("project").join(project_path)
+        } else {
+            path.strip_prefix(dir.parent().unwrap())
+                .map_err(strip_error)?
+                .to_path_buf()
+        };
+        let digest_path = project_path
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        match kind {
+            EntryKind::File => {
+                let file = std::fs::File::open(&path)?;
+                let mut header = tar::Header::new_gnu();
+                header.set_metadata_in_mode(&file.metadata()?, HeaderMode::Deterministic);
+
+                // Each file contributes its permissions, size and path to the digest, followed
+                // by its contents as they are read into the archive
+                hasher.update(format!(
+                    "{:o} {} {}\0",
+                    header.mode()?,
+                    header.size()?,
+                    digest_path
+                ));
+                let reader = DigestReader {
+                    inner: file,
+                    hasher: &mut hasher,
+                };
+                tar.append_data(&mut header, relative_path, reader)?;
+            }
+            EntryKind::Symlink(target) => {
+                let mut header = tar::Header::new_gnu();
+                header.set_metadata_in_mode(
+                    &std::fs::symlink_metadata(&path)?,
+                    HeaderMode::Deterministic,
+                );
+
+                // Links contribute their target in place of contents
+                hasher.update(format!(
+                    "{:o} {} {}\0",
+                    header.mode()?,
+                    header.size()?,
+                    digest_path
+                ));
+                hasher.update(target.to_string_lossy().as_bytes());
+                tar.append_link(&mut header, relative_path, target)?;
+            }
+            EntryKind::Dir => {}
+            EntryKind::Other => warn!(
+                "Skipping {}, which is not a regular file or directory",
+                path.display()
+            ),
+        }
+    }
+    let mut writer = tar.into_inner()?.finish()?;
+    writer.flush()?;
+    Ok((writer, format_digest(hasher)))
+}
+
 /// When a user submits a path to the circuit create method, we prepare the directory
 /// of the circuit project as a compressed tarfile which is sent as multipart/form data.
+/// The tarfile is written to a temporary file through a bounded buffer, so that projects
+/// of any size can be packaged without holding them in memory.
 ///
-/// Validation checks ensure the project contains a valid Sindri manifest and that the upload
-/// size is within the allowed limits (8Gb by default).
+/// Packaging is reproducible: files are added in sorted order with normalized timestamps,
+/// owners and permissions, under a fixed gzip header. The returned archive carries a SHA-256
+/// digest of the project contents.
+///
+/// Validation checks ensure the project contains a Sindri manifest which matches `manifest_schema`
+/// and that the upload size is within the allowed limits (8Gb by default).
 ///
 /// If the project contains a .sindriignore file, that file is treated in the convention of .gitignore.
 /// Files matchings those patterns are not included in the upload to Sindri.  
-/// Hidden and .gitignored files are similarly not included.
+/// Hidden and .gitignored files are similarly not included, unless `options` say otherwise.
+/// Symbolic links are followed, preserved or rejected according to `options`.
 pub async fn compress_directory(
     dir: &Path,
-    override_max_project_size: Option<usize>,
-) -> Result<Vec<u8>, Box<dyn Error>> {
+    manifest_schema: &serde_json::Value,
+    options: &PackageOptions,
+) -> Result<ProjectArchive, Error> {
     #[cfg(feature = "rich-terminal")]
     println!("{}", style("Preparing circuit files...").bold());
-    // Check for Sindri manifest
-    let manifest_path = dir.join(SINDRI_MANIFEST_FILENAME);
-    if !manifest_path.exists() {
-        return Err(format!("{} not found in project root", SINDRI_MANIFEST_FILENAME).into());
-    }
-
-    // Validate JSON
-    let mut manifest_file = std::fs::File::open(&manifest_path)?;
-    let mut manifest_contents = String::new();
-    manifest_file.read_to_string(&mut manifest_contents)?;
-
-    serde_json::from_str::<serde_json::Value>(&manifest_contents)
-        .map_err(|e| format!("Invalid JSON in {}: {}", SINDRI_MANIFEST_FILENAME, e))?;
+    // Check the Sindri manifest against the schema
+    let manifest = read_manifest(dir)?;
+    validate_manifest_against(&manifest, manifest_schema)?;
+    let manifest = SindriManifest::from_json(manifest)?;
+    info!(
+        "Found {} manifest for project {}",
+        manifest.circuit_type(),
+        manifest.name()
+    );
 
     #[cfg(feature = "rich-terminal")]
-    println!("{}", style("  ✓ Valid Sindri manifest found").cyan());
+    println!(
+        "{}",
+        style(format!(
+            "  ✓ Valid Sindri manifest found for {} project {}",
+            manifest.circuit_type(),
+            manifest.name()
+        ))
+        .cyan()
+    );
 
-    let mut contents = Vec::new();
-    {
+    let spool = tempfile::Builder::new()
+        .prefix("sindri-upload-")
+        .suffix(".tar.gz")
+        .tempfile()?;
+    let digest = {
         #[cfg(feature = "rich-terminal")]
         let pb = ProgressBar::new_spinner();
         #[cfg(feature = "rich-terminal")]
@@ -113,38 +476,27 @@ pub async fn compress_directory(
         #[cfg(feature = "rich-terminal")]
         pb.set_message("Compressing project files...");
 
-        let buffer = std::io::Cursor::new(&mut contents);
-        let enc = GzEncoder::new(buffer, Compression::default());
-        let mut tar = tar::Builder::new(enc);
-
-        // walk the directory with exclusions
-        // hidden, git_ignore, git_exclude, etc are all on by default
-        let walker = WalkBuilder::new(dir)
-            .add_custom_ignore_filename(SINDRI_IGNORE_FILENAME)
-            .build();
-
-        for entry in walker.filter_map(Result::ok) {
-            let path = entry.path();
-            if path.is_file() {
-                let relative_path = if dir == Path::new(".") {
-                    Path::new("project").join(path.strip_prefix(dir)?)
-                } else {
-                    path.strip_prefix(dir.parent().unwrap())?.to_path_buf()
-                };
-                tar.append_file(relative_path, &mut std::fs::File::open(path)?)?;
-            }
-        }
-    }
+        let (_, digest) = write_project_archive(dir, options, BufWriter::new(spool.as_file()))?;
+        digest
+    };
+    let spool = spool.into_temp_path();
+    let archive = ProjectArchive {
+        size: std::fs::metadata(&spool)?.len(),
+        path: spool.to_path_buf(),
+        digest,
+        manifest: Some(manifest),
+        _spool: Some(spool),
+    };
 
     // Check the size of the upload
-    if contents.len() > override_max_project_size.unwrap_or(MAX_PROJECT_SIZE) {
-        return Err(format!(
+    if archive.size() > options.max_project_size {
+        return Err(Error::Packaging(format!(
             "This project directory exceeds the maximum allowed size of {} and requires a special compilation process. \
             Please reach out to the Sindri team if you would like to compile the entire project \
             or double check the contents of the project for files and directories that do not \
             need to be included. Those may be added to a `{}` if you would like to \
-            automatically exclude them on your next upload.", MAX_PROJECT_SIZE, SINDRI_IGNORE_FILENAME
-        ).into());
+            automatically exclude them on your next upload.", options.max_project_size, SINDRI_IGNORE_FILENAME
+        )));
     }
 
     #[cfg(feature = "rich-terminal")]
@@ -152,23 +504,259 @@ pub async fn compress_directory(
         "{}",
         style(format!(
             "  ✓ Successfully prepared {} upload",
-            format_size(contents.len())
+            format_size(archive.size())
         ))
         .cyan()
     );
 
-    Ok(contents)
+    Ok(archive)
+}
+
+/// Ignore files honored by the project walker in order of precedence, and whether each
+/// only applies within git repositories.
+const IGNORE_FILES: [(&str, bool); 3] = [
+    (SINDRI_IGNORE_FILENAME, false),
+    (".ignore", false),
+    (".gitignore", true),
+];
+
+/// Explains why the project walker skipped a path, by matching the path against the
+/// exclude patterns and the ignore files which apply to it.
+struct ExclusionExplainer {
+    root: PathBuf,
+    exclude: Gitignore,
+    honored_git_ignores: bool,
+    hidden_excluded: bool,
+    matchers: HashMap<PathBuf, Option<Gitignore>>,
+}
+
+impl ExclusionExplainer {
+    fn new(root: &Path, options: &PackageOptions) -> Result<Self, Error> {
+        let in_git_repo = root.ancestors().any(|dir| dir.join(GIT_DIR_NAME).exists());
+        Ok(Self {
+            root: root.to_path_buf(),
+            exclude: pattern_matcher(root, &options.exclude)?,
+            honored_git_ignores: in_git_repo && options.respect_gitignore,
+            hidden_excluded: !options.include_hidden,
+            matchers: HashMap::new(),
+        })
+    }
+
+    /// Returns the rules of the ignore file at `file`, whose patterns are relative to `dir`.
+    fn matcher(&mut self, dir: &Path, file: PathBuf) -> Option<&Gitignore> {
+        self.matchers
+            .entry(file)
+            .or_insert_with_key(|file| {
+                if !file.is_file() {
+                    return None;
+                }
+                let mut builder = GitignoreBuilder::new(dir);
+                builder.add(file);
+                builder.build().ok()
+            })
+            .as_ref()
+    }
+
+    fn explain(&mut self, path: &Path, is_dir: bool) -> ExclusionReason {
+        if path.file_name() == Some(OsStr::new(GIT_DIR_NAME)) {
+            return ExclusionReason::GitDirectory;
+        }
+        if let Ok(project_path) = path.strip_prefix(&self.root) {
+            if let Match::Ignore(glob) = self
+                .exclude
+                .matched_path_or_any_parents(project_path, is_dir)
+            {
+                return ExclusionReason::ExcludePattern {
+                    pattern: glob.original().to_string(),
+                };
+            }
+        }
+
+        // Each kind of ignore file takes precedence over the next, and among files of the
+        // same kind the one nearest to the path takes precedence
+        let mut kinds: Vec<Vec<(PathBuf, PathBuf)>> = IGNORE_FILES
+            .iter()
+            .filter(|(_, git_only)| self.honored_git_ignores || !git_only)
+            .map(|(name, _)| {
+                path.ancestors()
+                    .skip(1)
+                    .map(|dir| (dir.to_path_buf(), dir.join(name)))
+                    .collect()
+            })
+            .collect();
+        if self.honored_git_ignores {
+            if let Some(repo) = path.ancestors().find(|dir| dir.join(GIT_DIR_NAME).is_dir()) {
+                kinds.push(vec![(repo.to_path_buf(), repo.join(".git/info/exclude"))]);
+            }
+        }
+
+        for (dir, file) in kinds.into_iter().flatten() {
+            let Some(matcher) = self.matcher(&dir, file.clone()) else {
+                continue;
+            };
+            match matcher.matched_path_or_any_parents(path, is_dir) {
+                Match::Ignore(glob) => {
+                    let pattern = glob.original().to_string();
+                    return ExclusionReason::IgnoreRule {
+                        line: line_of_pattern(&file, &pattern),
+                        file: file.strip_prefix(&self.root).unwrap_or(&file).to_path_buf(),
+                        pattern,
+                    };
+                }
+                Match::Whitelist(_) => return ExclusionReason::Other,
+                Match::None => {}
+            }
+        }
+
+        let is_hidden = path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+        if self.hidden_excluded && is_hidden {
+            ExclusionReason::Hidden
+        } else if !is_dir && !path.is_file() {
+            // Broken links and other entries which the walker could not read
+            ExclusionReason::NotRegularFile
+        } else {
+            ExclusionReason::Other
+        }
+    }
+}
+
+/// Finds the line of an ignore file on which a pattern is written, starting at 1.
+fn line_of_pattern(file: &Path, pattern: &str) -> Option<usize> {
+    std::fs::read_to_string(file)
+        .ok()?
+        .lines()
+        .position(|line| line == pattern || line.trim_end() == pattern)
+        .map(|index| index + 1)
+}
+
+/// Writer which discards its input, counting the bytes written.
+struct CountingWriter(u64);
+
+impl Write for CountingWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0 += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Reports which files of a project directory would be packaged, and why the others would
+/// be left out. The projected archive size is measured by compressing the included files
+/// without writing the archive anywhere.
+pub(crate) fn plan_directory(dir: &Path, options: &PackageOptions) -> Result<PackagePlan, Error> {
+    let root = dir.canonicalize()?;
+    let walked = walk_project(&root, options)?;
+    let mut plan = PackagePlan {
+        included: Vec::new(),
+        excluded: Vec::new(),
+        compressed_size: 0,
+        max_size: options.max_project_size,
+    };
+    let mut explainer = ExclusionExplainer::new(&root, options)?;
+    plan_entries(&root, &root, &walked, &mut explainer, &mut plan)?;
+
+    let (CountingWriter(compressed_size), _) =
+        write_project_archive(dir, options, CountingWriter(0))?;
+    plan.compressed_size = compressed_size;
+    Ok(plan)
+}
+
+/// Adds the entries of a directory to the plan in walk order, descending into the
+/// directories which the project walker entered.
+fn plan_entries(
+    root: &Path,
+    dir: &Path,
+    walked: &BTreeMap<PathBuf, EntryKind>,
+    explainer: &mut ExclusionExplainer,
+    plan: &mut PackagePlan,
+) -> Result<(), Error> {
+    let mut entries = std::fs::read_dir(dir)?.collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        let path = entry.path();
+        let project_path = path.strip_prefix(root).map_err(strip_error)?.to_path_buf();
+        let is_dir = path.is_dir();
+        match walked.get(&path) {
+            None => plan.excluded.push(ExcludedPath {
+                path: project_path,
+                is_dir,
+                reason: explainer.explain(&path, is_dir),
+            }),
+            Some(EntryKind::Dir) => plan_entries(root, &path, walked, explainer, plan)?,
+            Some(EntryKind::File) => plan.included.push(PlannedFile {
+                path: project_path,
+                size: std::fs::metadata(&path)?.len(),
+            }),
+            Some(EntryKind::Symlink(_)) => plan.included.push(PlannedFile {
+                path: project_path,
+                size: 0,
+            }),
+            Some(EntryKind::Other) => plan.excluded.push(ExcludedPath {
+                path: project_path,
+                is_dir,
+                reason: ExclusionReason::NotRegularFile,
+            }),
+        }
+    }
+    Ok(())
+}
+
+/// Reads and parses the Sindri manifest in the root of a project directory.
+pub(crate) fn read_manifest(dir: &Path) -> Result<serde_json::Value, Error> {
+    let manifest_path = dir.join(SINDRI_MANIFEST_FILENAME);
+    if !manifest_path.exists() {
+        return Err(Error::Packaging(format!(
+            "{} not found in project root",
+            SINDRI_MANIFEST_FILENAME
+        )));
+    }
+
+    // Validate JSON
+    let mut manifest_file = std::fs::File::open(&manifest_path)?;
+    let mut manifest_contents = String::new();
+    manifest_file.read_to_string(&mut manifest_contents)?;
+
+    serde_json::from_str(&manifest_contents).map_err(|e| {
+        Error::Packaging(format!(
+            "Invalid JSON in {}: {}",
+            SINDRI_MANIFEST_FILENAME, e
+        ))
+    })
+}
+
+fn strip_error(e: std::path::StripPrefixError) -> Error {
+    Error::Packaging(format!("Could not resolve project file path: {}", e))
+}
+
+/// Parses a timestamp returned by the Sindri API (e.g. `date_created`).
+///
+/// Timestamps are RFC 3339 strings, but bare dates are accepted as midnight UTC.
+pub(crate) fn parse_api_datetime(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|datetime| datetime.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .map(|datetime| datetime.and_utc())
+        })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::client::SindriClient;
+    use crate::{client::SindriClient, manifest::bundled_manifest_schema};
 
     use std::{
-        collections::HashSet,
+        collections::{HashMap, HashSet},
         fs::{self, File},
-        io::{Cursor, Write},
+        io::Write,
         path::PathBuf,
     };
 
@@ -176,6 +764,13 @@ mod tests {
     use tar::Archive;
     use tempfile::TempDir;
 
+    fn max_size(max_project_size: u64) -> PackageOptions {
+        PackageOptions {
+            max_project_size,
+            ..Default::default()
+        }
+    }
+
     fn create_test_directory() -> (TempDir, PathBuf) {
         let temp_dir = TempDir::new().unwrap();
         let dir_path = temp_dir.path().to_path_buf();
@@ -198,11 +793,103 @@ mod tests {
     async fn test_successful_compression() {
         let (_temp_dir, dir_path) = create_test_directory();
 
-        let result = compress_directory(&dir_path, None).await;
+        let result = compress_directory(
+            &dir_path,
+            &bundled_manifest_schema(),
+            &PackageOptions::default(),
+        )
+        .await;
         assert!(result.is_ok());
 
-        let compressed_data = result.unwrap();
-        assert!(!compressed_data.is_empty());
+        let archive = result.unwrap();
+        assert!(archive.size() > 0);
+        assert_eq!(fs::metadata(archive.path()).unwrap().len(), archive.size());
+
+        // The spooled archive is removed along with the archive
+        let spool_path = archive.path().to_path_buf();
+        drop(archive);
+        assert!(!spool_path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_deterministic_packaging() {
+        let (_temp_dir, dir_path) = create_test_directory();
+        fs::create_dir(dir_path.join("lib")).unwrap();
+        fs::write(
+            dir_path.join("lib").join("helper.circom"),
+            "template A() {}",
+        )
+        .unwrap();
+
+        let first = compress_directory(
+            &dir_path,
+            &bundled_manifest_schema(),
+            &PackageOptions::default(),
+        )
+        .await
+        .unwrap();
+
+        // Touching a file does not change the archive
+        let artifact = File::options()
+            .write(true)
+            .open(dir_path.join("some_artifact.circom"))
+            .unwrap();
+        artifact
+            .set_modified(std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1))
+            .unwrap();
+        let second = compress_directory(
+            &dir_path,
+            &bundled_manifest_schema(),
+            &PackageOptions::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            fs::read(first.path()).unwrap(),
+            fs::read(second.path()).unwrap()
+        );
+        assert_eq!(first.digest(), second.digest());
+        assert!(first.digest().starts_with("sha256:"));
+
+        // Entries are sorted with normalized metadata
+        let mut archive = Archive::new(GzDecoder::new(File::open(first.path()).unwrap()));
+        let entries: Vec<_> = archive
+            .entries()
+            .unwrap()
+            .map(|entry| {
+                let entry = entry.unwrap();
+                let header = entry.header();
+                assert_eq!(header.uid().unwrap(), 0);
+                assert_eq!(header.gid().unwrap(), 0);
+                entry.path().unwrap().into_owned()
+            })
+            .collect();
+        let mut sorted = entries.clone();
+        sorted.sort();
+        assert_eq!(entries, sorted);
+
+        // The digest changes with the contents, but not with the directory name
+        fs::write(dir_path.join("some_artifact.circom"), b"new content").unwrap();
+        let changed = compress_directory(
+            &dir_path,
+            &bundled_manifest_schema(),
+            &PackageOptions::default(),
+        )
+        .await
+        .unwrap();
+        assert_ne!(changed.digest(), first.digest());
+
+        let other_dir = TempDir::new().unwrap();
+        let renamed_path = other_dir.path().join("renamed-project");
+        fs::rename(&dir_path, &renamed_path).unwrap();
+        let renamed = compress_directory(
+            &renamed_path,
+            &bundled_manifest_schema(),
+            &PackageOptions::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(renamed.digest(), changed.digest());
     }
 
     #[tokio::test]
@@ -210,7 +897,12 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let dir_path = temp_dir.path().to_path_buf();
 
-        let result = compress_directory(&dir_path, None).await;
+        let result = compress_directory(
+            &dir_path,
+            &bundled_manifest_schema(),
+            &PackageOptions::default(),
+        )
+        .await;
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("not found"));
     }
@@ -223,11 +915,39 @@ mod tests {
         let manifest_path = dir_path.join(SINDRI_MANIFEST_FILENAME);
         fs::write(manifest_path, "nonjson").unwrap();
 
-        let result = compress_directory(&dir_path, None).await;
+        let result = compress_directory(
+            &dir_path,
+            &bundled_manifest_schema(),
+            &PackageOptions::default(),
+        )
+        .await;
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Invalid JSON"));
     }
 
+    #[tokio::test]
+    async fn test_manifest_schema_violation() {
+        let (_temp_dir, dir_path) = create_test_directory();
+
+        // Overwrite with a manifest which does not name its framework
+        let manifest_path = dir_path.join(SINDRI_MANIFEST_FILENAME);
+        fs::write(manifest_path, r#"{"name": "test", "circuitType": ""}"#).unwrap();
+
+        let result = compress_directory(
+            &dir_path,
+            &bundled_manifest_schema(),
+            &PackageOptions::default(),
+        )
+        .await;
+        match result.unwrap_err() {
+            Error::InvalidManifest { violations } => {
+                assert_eq!(violations.len(), 1);
+                assert_eq!(violations[0].path, "/circuitType");
+            }
+            e => panic!("Unexpected error: {}", e),
+        }
+    }
+
     #[tokio::test]
     async fn test_sindriignore_respected() {
         let (_temp_dir, dir_path) = create_test_directory();
@@ -237,11 +957,16 @@ mod tests {
         fs::write(dir_path.join(SINDRI_IGNORE_FILENAME), ignore_content).unwrap();
         fs::write(dir_path.join("ignored.txt"), "should be ignored").unwrap();
 
-        let circuit = compress_directory(&dir_path, None).await;
+        let circuit = compress_directory(
+            &dir_path,
+            &bundled_manifest_schema(),
+            &PackageOptions::default(),
+        )
+        .await;
         assert!(circuit.is_ok());
 
-        let cursor = Cursor::new(circuit.unwrap());
-        let gz_decoder = GzDecoder::new(cursor);
+        let archive = circuit.unwrap();
+        let gz_decoder = GzDecoder::new(File::open(archive.path()).unwrap());
         let mut archive = Archive::new(gz_decoder);
 
         let file_names: Vec<String> = archive
@@ -260,11 +985,16 @@ mod tests {
 
         fs::write(dir_path.join(".hidden"), "hidden content").unwrap();
 
-        let circuit = compress_directory(&dir_path, None).await;
+        let circuit = compress_directory(
+            &dir_path,
+            &bundled_manifest_schema(),
+            &PackageOptions::default(),
+        )
+        .await;
         assert!(circuit.is_ok());
 
-        let cursor = Cursor::new(circuit.unwrap());
-        let gz_decoder = GzDecoder::new(cursor);
+        let archive = circuit.unwrap();
+        let gz_decoder = GzDecoder::new(File::open(archive.path()).unwrap());
         let mut archive = Archive::new(gz_decoder);
 
         let file_names: Vec<String> = archive
@@ -277,6 +1007,257 @@ mod tests {
         assert!(!file_names.contains(&".hidden".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_plan_package() {
+        let (_temp_dir, dir_path) = create_test_directory();
+        fs::write(
+            dir_path.join(SINDRI_IGNORE_FILENAME),
+            "# build outputs\n*.log\ntarget/\n",
+        )
+        .unwrap();
+        fs::write(dir_path.join("build.log"), "compiling").unwrap();
+        fs::create_dir(dir_path.join("target")).unwrap();
+        fs::write(dir_path.join("target").join("circuit.r1cs"), "r1cs").unwrap();
+        fs::write(dir_path.join(".env"), "SECRET=1").unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(dir_path.join("target"), dir_path.join("linked")).unwrap();
+
+        let plan = plan_directory(&dir_path, &PackageOptions::default()).unwrap();
+        assert_eq!(
+            plan.included,
+            vec![
+                PlannedFile {
+                    path: PathBuf::from("sindri.json"),
+                    size: 49,
+                },
+                PlannedFile {
+                    path: PathBuf::from("some_artifact.circom"),
+                    size: 12,
+                },
+            ]
+        );
+        assert_eq!(plan.total_size(), 61);
+
+        let reasons: HashMap<_, _> = plan
+            .excluded
+            .iter()
+            .map(|excluded| (excluded.path.to_str().unwrap(), &excluded.reason))
+            .collect();
+        assert_eq!(reasons[".env"], &ExclusionReason::Hidden);
+        assert_eq!(reasons[SINDRI_IGNORE_FILENAME], &ExclusionReason::Hidden);
+        assert_eq!(
+            reasons["build.log"],
+            &ExclusionReason::IgnoreRule {
+                file: PathBuf::from(SINDRI_IGNORE_FILENAME),
+                line: Some(2),
+                pattern: "*.log".to_string(),
+            }
+        );
+        // Ignored directories are reported without their contents
+        assert_eq!(
+            reasons["target"],
+            &ExclusionReason::IgnoreRule {
+                file: PathBuf::from(SINDRI_IGNORE_FILENAME),
+                line: Some(3),
+                pattern: "target/".to_string(),
+            }
+        );
+        assert!(!reasons.contains_key("target/circuit.r1cs"));
+        #[cfg(unix)]
+        assert_eq!(reasons["linked"], &ExclusionReason::NotRegularFile);
+
+        // The projected size is that of the packaged archive
+        let archive = compress_directory(
+            &dir_path,
+            &bundled_manifest_schema(),
+            &PackageOptions::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(plan.compressed_size, archive.size());
+        assert_eq!(plan.max_size, MAX_PROJECT_SIZE);
+        assert!(!plan.exceeds_max_size());
+        assert!(plan_directory(&dir_path, &max_size(10))
+            .unwrap()
+            .exceeds_max_size());
+    }
+
+    /// Lists the paths and entry types of a packaged archive, relative to the project.
+    fn archive_entries(archive: &ProjectArchive) -> HashMap<String, tar::EntryType> {
+        let mut archive = Archive::new(GzDecoder::new(File::open(archive.path()).unwrap()));
+        archive
+            .entries()
+            .unwrap()
+            .map(|entry| {
+                let entry = entry.unwrap();
+                let path: PathBuf = entry.path().unwrap().components().skip(1).collect();
+                (
+                    path.to_string_lossy().into_owned(),
+                    entry.header().entry_type(),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_package_options_select_files() {
+        let (_temp_dir, dir_path) = create_test_directory();
+        fs::create_dir_all(dir_path.join(".git")).unwrap();
+        fs::write(dir_path.join(".git").join("config"), "[credential]").unwrap();
+        fs::create_dir_all(dir_path.join(".cargo")).unwrap();
+        fs::write(dir_path.join(".cargo").join("config.toml"), "[build]").unwrap();
+        fs::write(dir_path.join(".gitignore"), "secret.txt\n").unwrap();
+        fs::write(dir_path.join("secret.txt"), "secret").unwrap();
+        fs::write(dir_path.join(SINDRI_IGNORE_FILENAME), "target/\n").unwrap();
+        fs::create_dir_all(dir_path.join("target")).unwrap();
+        fs::write(dir_path.join("target").join("keep.bin"), "keep").unwrap();
+        fs::write(dir_path.join("target").join("drop.bin"), "drop").unwrap();
+        fs::write(dir_path.join("notes.log"), "notes").unwrap();
+
+        let archive = compress_directory(
+            &dir_path,
+            &bundled_manifest_schema(),
+            &PackageOptions::default(),
+        )
+        .await
+        .unwrap();
+        let entries = archive_entries(&archive);
+        assert!(!entries.contains_key(".cargo/config.toml"));
+        assert!(!entries.contains_key("secret.txt"));
+        assert!(!entries.contains_key("target/keep.bin"));
+        assert!(entries.contains_key("notes.log"));
+
+        let options = PackageOptions {
+            include_hidden: true,
+            respect_gitignore: false,
+            include: vec!["target/keep.bin".to_string()],
+            exclude: vec!["*.log".to_string()],
+            ..Default::default()
+        };
+        let archive = compress_directory(&dir_path, &bundled_manifest_schema(), &options)
+            .await
+            .unwrap();
+        let entries = archive_entries(&archive);
+        assert!(entries.contains_key(".cargo/config.toml"));
+        assert!(entries.contains_key("secret.txt"));
+        assert!(entries.contains_key("target/keep.bin"));
+        assert!(!entries.contains_key("target/drop.bin"));
+        assert!(!entries.contains_key("notes.log"));
+        // Git repository data is left out even though hidden files are packaged
+        assert!(entries.contains_key(".gitignore"));
+        assert!(!entries.contains_key(".git/config"));
+
+        let plan = plan_directory(&dir_path, &options).unwrap();
+        let reasons: HashMap<_, _> = plan
+            .excluded
+            .iter()
+            .map(|excluded| (excluded.path.to_str().unwrap(), &excluded.reason))
+            .collect();
+        assert_eq!(
+            reasons["notes.log"],
+            &ExclusionReason::ExcludePattern {
+                pattern: "*.log".to_string(),
+            }
+        );
+        assert_eq!(reasons[".git"], &ExclusionReason::GitDirectory);
+        assert_eq!(
+            reasons["target/drop.bin"],
+            &ExclusionReason::IgnoreRule {
+                file: PathBuf::from(SINDRI_IGNORE_FILENAME),
+                line: Some(1),
+                pattern: "target/".to_string(),
+            }
+        );
+
+        let error = compress_directory(
+            &dir_path,
+            &bundled_manifest_schema(),
+            &PackageOptions {
+                include: vec!["a/**/b/[".to_string()],
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(error, Error::Validation(_)));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_package_symlinks() {
+        let (_temp_dir, dir_path) = create_test_directory();
+        fs::create_dir(dir_path.join("shared")).unwrap();
+        fs::write(
+            dir_path.join("shared").join("lib.circom"),
+            "template Lib() {}",
+        )
+        .unwrap();
+        std::os::unix::fs::symlink("shared", dir_path.join("lib")).unwrap();
+        std::os::unix::fs::symlink("some_artifact.circom", dir_path.join("link.circom")).unwrap();
+
+        let package = |symlinks| {
+            let dir_path = dir_path.clone();
+            async move {
+                let options = PackageOptions {
+                    symlinks,
+                    ..Default::default()
+                };
+                compress_directory(&dir_path, &bundled_manifest_schema(), &options).await
+            }
+        };
+
+        // By default only links to files are followed
+        let entries = archive_entries(&package(SymlinkMode::default()).await.unwrap());
+        assert_eq!(entries["link.circom"], tar::EntryType::Regular);
+        assert!(!entries.contains_key("lib"));
+        assert!(!entries.contains_key("lib/lib.circom"));
+
+        let followed = package(SymlinkMode::Follow).await.unwrap();
+        let entries = archive_entries(&followed);
+        assert_eq!(entries["lib/lib.circom"], tar::EntryType::Regular);
+        assert_eq!(entries["link.circom"], tar::EntryType::Regular);
+
+        let preserved = package(SymlinkMode::Preserve).await.unwrap();
+        let entries = archive_entries(&preserved);
+        assert_eq!(entries["lib"], tar::EntryType::Symlink);
+        assert_eq!(entries["link.circom"], tar::EntryType::Symlink);
+        assert!(!entries.contains_key("lib/lib.circom"));
+        assert_ne!(followed.digest(), preserved.digest());
+
+        let error = package(SymlinkMode::Error).await.unwrap_err();
+        assert!(matches!(error, Error::Packaging(message) if message.contains("symbolic link")));
+
+        // Preserved links may not point outside of the project once extracted
+        let outside = TempDir::new().unwrap();
+        for target in [outside.path(), Path::new("../../outside")] {
+            let link = dir_path.join("shared").join("escape");
+            std::os::unix::fs::symlink(target, &link).unwrap();
+            let error = package(SymlinkMode::Preserve).await.unwrap_err();
+            assert!(
+                matches!(error, Error::Packaging(ref message) if message.contains("outside of the project")),
+                "{error}"
+            );
+            fs::remove_file(link).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_stays_within_project() {
+        assert!(stays_within_project(Path::new("a/b"), 0));
+        assert!(stays_within_project(Path::new("../sibling"), 1));
+        assert!(stays_within_project(Path::new("./a/../.."), 1));
+        assert!(!stays_within_project(Path::new(".."), 0));
+        assert!(!stays_within_project(Path::new("a/../../.."), 1));
+        assert!(!stays_within_project(Path::new("/etc/passwd"), 3));
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536 * 1024), "1.50 MB");
+        assert_eq!(format_size(MAX_PROJECT_SIZE), "8.00 GB");
+    }
+
     #[tokio::test]
     async fn test_max_project_size_exceeded() {
         let (_temp_dir, dir_path) = create_test_directory();
@@ -287,7 +1268,8 @@ mod tests {
         fs::write(test_file_path, content).unwrap();
 
         // Set max size to 100 bytes
-        let result = compress_directory(&dir_path, Some(100)).await;
+        let result =
+            compress_directory(&dir_path, &bundled_manifest_schema(), &max_size(100)).await;
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -331,12 +1313,16 @@ mod tests {
         // Create a test directory with the current directory as the base
         let (_temp_dir, dir_path) = create_test_directory();
 
-        let result = compress_directory(&dir_path, None).await;
+        let result = compress_directory(
+            &dir_path,
+            &bundled_manifest_schema(),
+            &PackageOptions::default(),
+        )
+        .await;
         assert!(result.is_ok());
 
-        let compressed_data = result.unwrap();
-        let cursor = Cursor::new(compressed_data);
-        let gz_decoder = GzDecoder::new(cursor);
+        let archive = result.unwrap();
+        let gz_decoder = GzDecoder::new(File::open(archive.path()).unwrap());
         let mut archive = Archive::new(gz_decoder);
 
         let mut top_level_dirs = HashSet::new();
@@ -366,12 +1352,16 @@ mod tests {
         // Change the current directory to the test directory
         std::env::set_current_dir(&dir_path).unwrap();
 
-        let result = compress_directory(Path::new("."), None).await;
+        let result = compress_directory(
+            Path::new("."),
+            &bundled_manifest_schema(),
+            &PackageOptions::default(),
+        )
+        .await;
         assert!(result.is_ok());
 
-        let compressed_data = result.unwrap();
-        let cursor = Cursor::new(compressed_data);
-        let gz_decoder = GzDecoder::new(cursor);
+        let archive = result.unwrap();
+        let gz_decoder = GzDecoder::new(File::open(archive.path()).unwrap());
         let mut archive = Archive::new(gz_decoder);
 
         let mut top_level_dirs = HashSet::new();
@@ -4,7 +4,7 @@ use sindri::{client::SindriClient, CircuitInfo, CircuitInfoResponse, JobStatus};
 
 #[tokio::test]
 async fn end_to_end() {
-    // Use prepped tarball since gzip encoding is nondeterministic
+    // Use prepped tarball, which matches the recorded upload
     let dir_path = "../cli/tests/factory/circuit.tar.gz";
 
     let client = SindriClient::new(None, None);